@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::bucket::Owner;
+
+/// 访问权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Acl {
+    /// 私有
+    #[serde(rename = "private")]
+    Private,
+    /// 公共读
+    #[serde(rename = "public-read")]
+    PublicRead,
+    /// 公共读写
+    #[serde(rename = "public-read-write")]
+    PublicReadWrite,
+    /// 继承bucket的权限，仅用于object
+    #[serde(rename = "default")]
+    Default,
+}
+
+impl Display for Acl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acl::Private => write!(f, "private"),
+            Acl::PublicRead => write!(f, "public-read"),
+            Acl::PublicReadWrite => write!(f, "public-read-write"),
+            Acl::Default => write!(f, "default"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccessControlList {
+    pub grant: Acl,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "AccessControlPolicy", rename_all = "PascalCase")]
+pub(crate) struct AccessControlPolicy {
+    #[serde(default)]
+    pub(crate) owner: Owner,
+    pub(crate) access_control_list: AccessControlList,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::acl::{AccessControlPolicy, Acl};
+
+    #[test]
+    fn test_access_control_policy_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AccessControlPolicy>
+  <Owner>
+    <ID>0022012****</ID>
+    <DisplayName>user_example</DisplayName>
+  </Owner>
+  <AccessControlList>
+    <Grant>public-read</Grant>
+  </AccessControlList>
+</AccessControlPolicy>"#;
+        let policy: AccessControlPolicy = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(policy.owner.id, "0022012****");
+        assert_eq!(policy.access_control_list.grant, Acl::PublicRead);
+        assert_eq!(Acl::PublicReadWrite.to_string(), "public-read-write");
+    }
+}
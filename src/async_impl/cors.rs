@@ -0,0 +1,115 @@
+use crate::cors::{CorsConfiguration, PreflightResponse};
+use crate::debug;
+use crate::error::OssError;
+use crate::oss::{API, OSS, OSSInfo};
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 设置bucket的跨域规则(覆盖已有规则)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::cors::{CorsConfiguration, CorsRule};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = CorsConfiguration::new()
+    ///     .with_rule(CorsRule::new()
+    ///         .with_allowed_origin("https://www.example.com")
+    ///         .with_allowed_method("POST")
+    ///         .with_allowed_header("*"));
+    /// oss.put_bucket_cors(config, RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn put_bucket_cors(
+        &self,
+        config: CorsConfiguration,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("cors", "");
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&config)?;
+        self.send_request("/", build, Some(body.into_bytes())).await?;
+        Ok(())
+    }
+
+    /// 获取bucket的跨域规则
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = oss.get_bucket_cors(RequestBuilder::new()).await.unwrap();
+    /// println!("{:?}", config.rules);
+    /// ```
+    pub async fn get_bucket_cors(&self, build: RequestBuilder) -> Result<CorsConfiguration, OssError> {
+        let mut build = build.parameters_put("cors", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None).await?;
+        let text = response.text().await?;
+        Ok(quick_xml::de::from_str(&text)?)
+    }
+
+    /// 删除bucket的跨域规则
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.delete_bucket_cors(RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn delete_bucket_cors(&self, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build.parameters_put("cors", "");
+        build.method = RequestType::Delete;
+        self.send_request("/", build, None).await?;
+        Ok(())
+    }
+
+    /// 发送跨域预检请求(不签名)，没有匹配的跨域规则时返回错误
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::{RequestBuilder, RequestType};
+    /// let oss = OSS::from_env();
+    /// let preflight = oss.options_object(
+    ///     "/upload/test.txt",
+    ///     "https://www.example.com",
+    ///     RequestType::Post,
+    ///     &["content-type"],
+    ///     RequestBuilder::new(),
+    /// ).await.unwrap();
+    /// assert_eq!(preflight.allow_origin.as_deref(), Some("https://www.example.com"));
+    /// ```
+    pub async fn options_object<S: AsRef<str>, O: AsRef<str>>(
+        &self,
+        key: S,
+        origin: O,
+        method: RequestType,
+        request_headers: &[&str],
+        build: RequestBuilder,
+    ) -> Result<PreflightResponse, OssError> {
+        let key = self.format_key(key);
+        let url = self.format_host(self.bucket(), key.clone(), &build);
+        let origin = origin.as_ref();
+        debug!("oss log: OPTIONS url: {} origin: {}", url, origin);
+        let mut request = self
+            .client()
+            .http()
+            .request(RequestType::Options.into(), url)
+            .header("Origin", origin)
+            .header("Access-Control-Request-Method", method.to_string());
+        if !request_headers.is_empty() {
+            request = request.header("Access-Control-Request-Headers", request_headers.join(","));
+        }
+        let response = request.send().await?;
+        if response.status().is_success() {
+            Ok(PreflightResponse::new(response.headers()))
+        } else {
+            let status = response.status();
+            let result = response.text().await?;
+            debug!("oss log: OPTIONS {} status: {} error: {}", key, status, result);
+            Err(OssError::Err(format!(
+                "OPTIONS {} status: {} error: {}",
+                key, status, result
+            )))
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::Display;
+use crate::error::OssError;
+use crate::image::{save_as, ProcessObjectResult};
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 处理文件并将结果另存为`target_bucket`中的`target_key`，`target_bucket`为None时保存到当前bucket
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::image::{ImageProcess, Resize, ResizeMode};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let process = ImageProcess::new().with_resize(Resize::new(ResizeMode::Fill).with_width(200).with_height(200));
+    /// let result = oss.process_object("/photo.jpg", &process, Some("my-thumbnails"), "/thumb/photo.jpg", RequestBuilder::new()).await.unwrap();
+    /// println!("size: {}", result.file_size);
+    /// ```
+    pub async fn process_object<S: AsRef<str>, P: Display>(
+        &self,
+        source_key: S,
+        process: P,
+        target_bucket: Option<&str>,
+        target_key: &str,
+        build: RequestBuilder,
+    ) -> Result<ProcessObjectResult, OssError> {
+        let mut build = build.parameters_put("x-oss-process", "");
+        build.method = RequestType::Post;
+        let body = format!("x-oss-process={}", save_as(process, target_bucket, target_key));
+        let response = self.send_request(source_key, build, Some(body.into_bytes())).await?;
+        let text = response.text().await?;
+        let result: ProcessObjectResult = serde_json::from_str(&text)?;
+        Ok(result)
+    }
+}
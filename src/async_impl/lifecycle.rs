@@ -0,0 +1,67 @@
+use crate::error::OssError;
+use crate::lifecycle::LifecycleConfiguration;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 设置bucket的生命周期规则(覆盖已有规则)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::bucket::StorageClass;
+    /// use aliyun_oss_rust_sdk::lifecycle::{LifecycleConfiguration, LifecycleRule};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = LifecycleConfiguration::new()
+    ///     .with_rule(LifecycleRule::new("logs", "log/")
+    ///         .with_transition_days(30, StorageClass::IA)
+    ///         .with_expiration_days(365));
+    /// oss.put_bucket_lifecycle(config, RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn put_bucket_lifecycle(
+        &self,
+        config: LifecycleConfiguration,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("lifecycle", "");
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&config)?;
+        self.send_request("/", build, Some(body.into_bytes())).await?;
+        Ok(())
+    }
+
+    /// 获取bucket的生命周期规则
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = oss.get_bucket_lifecycle(RequestBuilder::new()).await.unwrap();
+    /// println!("{:?}", config.rules);
+    /// ```
+    pub async fn get_bucket_lifecycle(
+        &self,
+        build: RequestBuilder,
+    ) -> Result<LifecycleConfiguration, OssError> {
+        let mut build = build.parameters_put("lifecycle", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None).await?;
+        let text = response.text().await?;
+        Ok(quick_xml::de::from_str(&text)?)
+    }
+
+    /// 删除bucket的所有生命周期规则
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.delete_bucket_lifecycle(RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn delete_bucket_lifecycle(&self, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build.parameters_put("lifecycle", "");
+        build.method = RequestType::Delete;
+        self.send_request("/", build, None).await?;
+        Ok(())
+    }
+}
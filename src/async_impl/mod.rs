@@ -1 +1,63 @@
-mod object;
\ No newline at end of file
+mod acl;
+mod bucket;
+#[cfg(feature = "callback-verify")]
+mod callback;
+mod cors;
+#[cfg(feature = "encryption")]
+mod encryption;
+mod image;
+mod lifecycle;
+mod multipart;
+mod object;
+mod policy;
+mod referer;
+mod restore;
+mod sse;
+mod symlink;
+mod tagging;
+mod versioning;
+
+use reqwest::StatusCode;
+use crate::{debug, util};
+use crate::error::OssError;
+use crate::oss::{API, OSS};
+use crate::request::RequestBuilder;
+
+impl OSS {
+    /// 签名并发送请求，非2xx响应转换为错误
+    pub(crate) async fn send_request<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+        body: Option<Vec<u8>>,
+    ) -> Result<reqwest::Response, OssError> {
+        let key = self.format_key(key);
+        let mut build = build;
+        if build.content_md5_auto {
+            build.content_md5 = body.as_ref().map(util::md5_base64);
+        }
+        let method = build.method.clone();
+        let (url, headers) = self.build_request(key.as_str(), build)?;
+        debug!("oss log: {} url: {} headers: {:?}", method, url, headers);
+        let mut request = self.client().http().request(method.clone().into(), url).headers(headers);
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+        let response = request.send().await?;
+        if response.status() == StatusCode::NON_AUTHORITATIVE_INFORMATION {
+            //上传成功但回调失败
+            let result = response.text().await?;
+            Err(OssError::CallbackFailed(result))
+        } else if response.status().is_success() {
+            Ok(response)
+        } else {
+            let status = response.status();
+            let result = response.text().await?;
+            debug!("oss log: {} {} status: {} error: {}", method, key, status, result);
+            Err(OssError::Err(format!(
+                "{} {} status: {} error: {}",
+                method, key, status, result
+            )))
+        }
+    }
+}
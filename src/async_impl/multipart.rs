@@ -0,0 +1,146 @@
+use crate::crc64::{self, crc64};
+use crate::error::OssError;
+use crate::multipart::{AppendObjectResult, CompleteMultipartUpload, CompleteMultipartUploadResult, InitiateMultipartUploadResult, UploadPart};
+use crate::oss::{API, OSS};
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 追加上传，`position`为当前文件长度(新建时为0)，`init_crc64`为追加前整个文件的CRC64，
+    /// 追加到已有文件且不知道CRC64时传None，此时不做校验
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let first = oss.append_object("/app.log", "line 1\n".as_bytes(), 0, Some(0), RequestBuilder::new()).await.unwrap();
+    /// let second = oss.append_object("/app.log", "line 2\n".as_bytes(), first.next_position, first.crc64, RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn append_object<S: AsRef<str>>(
+        &self,
+        key: S,
+        buffer: &[u8],
+        position: u64,
+        init_crc64: Option<u64>,
+        build: RequestBuilder,
+    ) -> Result<AppendObjectResult, OssError> {
+        let init_crc64 = if position == 0 { Some(0) } else { init_crc64 };
+        let mut build = build
+            .parameters_put("append", "")
+            .parameters_put("position".to_string(), position.to_string());
+        build.method = RequestType::Post;
+        let response = self.send_request(key, build, Some(buffer.to_owned())).await?;
+        if let Some(init_crc64) = init_crc64 {
+            let crc = crc64::combine(init_crc64, crc64(buffer), buffer.len() as u64);
+            self.verify_crc64(response.headers(), crc)?;
+        }
+        Ok(AppendObjectResult::new(response.headers()))
+    }
+
+    /// 初始化分片上传，返回upload id，`build`中的元数据、标签、加密方式作用于最终的文件
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let upload_id = oss.initiate_multipart_upload("/big.bin", RequestBuilder::new()).await.unwrap();
+    /// let data = std::fs::read("./big.bin").unwrap();
+    /// let mut parts = vec![];
+    /// for (i, chunk) in data.chunks(5 * 1024 * 1024).enumerate() {
+    ///     let part = oss.upload_part("/big.bin", &upload_id, i as u32 + 1, chunk, RequestBuilder::new()).await.unwrap();
+    ///     parts.push(part);
+    /// }
+    /// oss.complete_multipart_upload("/big.bin", &upload_id, &parts, RequestBuilder::new()).await.unwrap();
+    /// ```
+    pub async fn initiate_multipart_upload<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+    ) -> Result<String, OssError> {
+        let build = self.resolve_content_type(&[key.as_ref()], None, build);
+        let mut build = build.parameters_put("uploads", "");
+        build.method = RequestType::Post;
+        let response = self.send_request(key, build, None).await?;
+        let text = response.text().await?;
+        let result: InitiateMultipartUploadResult = quick_xml::de::from_str(&text)?;
+        Ok(result.upload_id)
+    }
+
+    /// 上传分片，除最后一个分片外每个分片至少100KB
+    pub async fn upload_part<S: AsRef<str>>(
+        &self,
+        key: S,
+        upload_id: &str,
+        part_number: u32,
+        buffer: &[u8],
+        build: RequestBuilder,
+    ) -> Result<UploadPart, OssError> {
+        let mut build = build
+            .parameters_put("uploadId", upload_id)
+            .parameters_put("partNumber".to_string(), part_number.to_string());
+        build.method = RequestType::Put;
+        let crc = crc64(buffer);
+        let response = self.send_request(key, build, Some(buffer.to_owned())).await?;
+        self.verify_crc64(response.headers(), crc)?;
+        let etag = response
+            .headers()
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim_matches('"').to_string())
+            .ok_or_else(|| OssError::Err("upload part response has no etag".to_string()))?;
+        Ok(UploadPart {
+            part_number,
+            etag,
+            size: buffer.len() as u64,
+            crc64: crc,
+        })
+    }
+
+    /// 完成分片上传，并用各分片的CRC64合并结果校验最终文件，`build`中可以设置上传回调
+    pub async fn complete_multipart_upload<S: AsRef<str>>(
+        &self,
+        key: S,
+        upload_id: &str,
+        parts: &[UploadPart],
+        build: RequestBuilder,
+    ) -> Result<CompleteMultipartUploadResult, OssError> {
+        let mut build = build.parameters_put("uploadId", upload_id);
+        build.method = RequestType::Post;
+        let callback = build.oss_headers.contains_key("x-oss-callback");
+        let (body, crc) = CompleteMultipartUpload::new(parts);
+        let body = quick_xml::se::to_string(&body)?;
+        let object = self.format_key(key.as_ref());
+        let response = self.send_request(key, build, Some(body.into_bytes())).await?;
+        self.verify_crc64(response.headers(), crc)?;
+        if callback {
+            //设置回调时响应内容为回调服务器返回的数据
+            let etag = response
+                .headers()
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            return Ok(CompleteMultipartUploadResult {
+                key: object.trim_start_matches('/').to_string(),
+                etag,
+                callback_body: response.bytes().await?.to_vec(),
+                ..Default::default()
+            });
+        }
+        let text = response.text().await?;
+        let result: CompleteMultipartUploadResult = quick_xml::de::from_str(&text)?;
+        Ok(result)
+    }
+
+    /// 取消分片上传，已上传的分片会被删除
+    pub async fn abort_multipart_upload<S: AsRef<str>>(
+        &self,
+        key: S,
+        upload_id: &str,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("uploadId", upload_id);
+        build.method = RequestType::Delete;
+        self.send_request(key, build, None).await?;
+        Ok(())
+    }
+}
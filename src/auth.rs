@@ -1,10 +1,43 @@
 use base64::Engine;
 use base64::engine::general_purpose;
+use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
 use reqwest::header::DATE;
+use sha2::{Digest, Sha256};
 use crate::debug;
+use crate::error::OssError;
 use crate::oss::{API, OSS, OSSInfo};
 use crate::request::{RequestBuilder};
+use crate::util::hex;
+
+/// 签名版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignVersion {
+    /// HMAC-SHA1
+    #[default]
+    V1,
+    /// OSS4-HMAC-SHA256，需要设置地域
+    V4,
+}
+
+/// V1签名中参与CanonicalizedResource计算的子资源，其它查询参数(例如prefix)不参与签名
+pub(crate) const SUB_RESOURCES: &[&str] = &[
+    "acl", "append", "asyncFetch", "bucketInfo", "callback", "callback-var", "cname", "comp",
+    "continuation-token", "cors", "delete", "encryption", "endTime", "img", "inventory",
+    "inventoryId", "lifecycle", "live", "location", "logging", "objectMeta", "partNumber",
+    "policy", "position", "qos", "qosInfo", "referer", "regionList", "replication",
+    "replicationLocation", "replicationProgress", "requestPayment", "resourceGroup",
+    "response-cache-control", "response-content-disposition", "response-content-encoding",
+    "response-content-language", "response-content-type", "response-expires", "restore",
+    "security-token", "sequential", "startTime", "stat", "status", "style", "styleName",
+    "symlink", "tagging", "transferAcceleration", "uploadId", "uploads", "versionId",
+    "versioning", "versions", "vod", "website", "worm", "wormExtend", "wormId",
+    "x-oss-ac-forwarded-for", "x-oss-ac-source-ip", "x-oss-ac-subnet-mask", "x-oss-ac-vpc-id",
+    "x-oss-process", "x-oss-request-payer", "x-oss-traffic-limit",
+];
+
+pub(crate) const V4_ALGORITHM: &str = "OSS4-HMAC-SHA256";
+pub(crate) const V4_UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
 
 pub trait AuthAPI {
     fn sign<S: AsRef<str>>(
@@ -18,9 +51,17 @@ pub trait AuthAPI {
         object: S,
         build: &RequestBuilder,
     ) -> String;
+
+    /// V4签名，返回Authorization头，build中需要包含`x-oss-date`和`x-oss-content-sha256`
+    fn oss_sign_v4<S: AsRef<str>>(
+        &self,
+        object: S,
+        build: &RequestBuilder,
+        now: DateTime<Utc>,
+    ) -> Result<String, OssError>;
 }
 
-impl<'a> AuthAPI for OSS {
+impl AuthAPI for OSS {
     fn sign<S: AsRef<str>>(
         &self,
         key: S,
@@ -38,29 +79,25 @@ impl<'a> AuthAPI for OSS {
 
         oss_headers.sort_by(|a, b| a.0.cmp(&b.0));
 
-        let mut canonicalized_oss_headers = oss_headers
+        let canonicalized_oss_headers = oss_headers
             .iter()
-            .map(|(k, v)| format!("{}:{}", k, v))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        if oss_headers.len() == 1 {
-            canonicalized_oss_headers = format!("{}\n", canonicalized_oss_headers);
-        }
+            .map(|(k, v)| format!("{}:{}\n", k, v.trim()))
+            .collect::<String>();
 
         let mut canonicalized_resource = self.format_oss_resource_str(self.bucket().as_str(), key.as_ref());
-        if build.parameters.len() > 0 {
-            let mut params = build
-                .parameters
-                .iter()
-                .collect::<Vec<_>>();
-            params.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut params = build
+            .parameters
+            .iter()
+            .filter(|(k, _)| SUB_RESOURCES.contains(&k.as_str()))
+            .collect::<Vec<_>>();
+        if !params.is_empty() {
+            params.sort_by(|a, b| a.0.cmp(b.0));
             canonicalized_resource = format!(
                 "{}?{}",
                 canonicalized_resource,
                 params
                     .into_iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
+                    .map(|(k, v)| if v.is_empty() { k.to_string() } else { format!("{}={}", k, v) })
                     .collect::<Vec<_>>()
                     .join("&")
             );
@@ -81,11 +118,165 @@ impl<'a> AuthAPI for OSS {
         let mut hasher: Hmac<sha1::Sha1> = Hmac::new_from_slice(self.key_secret().as_bytes()).unwrap();
         hasher.update(sign_str.as_bytes());
 
-        general_purpose::STANDARD.encode(&hasher.finalize().into_bytes())
+        general_purpose::STANDARD.encode(hasher.finalize().into_bytes())
     }
 
     fn oss_sign<S: AsRef<str>>(&self, object: S, build: &RequestBuilder) -> String {
         let sign_str_base64 = self.sign(object, build);
         format!("OSS {}:{}", self.key_id(), sign_str_base64)
     }
+
+    fn oss_sign_v4<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: &RequestBuilder,
+        now: DateTime<Utc>,
+    ) -> Result<String, OssError> {
+        let region = self
+            .client()
+            .region()
+            .ok_or_else(|| OssError::Err("V4 signature requires a region".to_string()))?;
+        let canonical_request = self.canonical_request_v4(key, build);
+        debug!("oss log: canonical request: {}", canonical_request);
+        let scope = v4_scope(&now, region.id());
+        let sign_str = format!(
+            "{}\n{}\n{}\n{}",
+            V4_ALGORITHM,
+            now.format("%Y%m%dT%H%M%SZ"),
+            scope,
+            hex(Sha256::digest(canonical_request.as_bytes())),
+        );
+        debug!("oss log: v4 sign_str: {}", sign_str);
+        let signing_key = v4_signing_key(self.key_secret().as_str(), &now, region.id());
+        let signature = hex(hmac_sha256(&signing_key, sign_str.as_bytes()));
+        Ok(format!(
+            "{} Credential={}/{},Signature={}",
+            V4_ALGORITHM,
+            self.key_id(),
+            scope,
+            signature
+        ))
+    }
+}
+
+impl OSS {
+    /// V4规范请求：所有查询参数、x-oss-*/content-type/content-md5请求头均参与签名
+    pub(crate) fn canonical_request_v4<S: AsRef<str>>(&self, key: S, build: &RequestBuilder) -> String {
+        let bucket = self.bucket();
+        let canonical_uri = if bucket.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}{}", bucket, self.key_urlencode(self.format_key(key)))
+        };
+        let mut headers = build
+            .oss_headers
+            .iter()
+            .map(|(k, v)| (k.to_lowercase(), v.trim().to_string()))
+            .collect::<Vec<_>>();
+        if let Some(content_type) = &build.content_type {
+            headers.push(("content-type".to_string(), content_type.trim().to_string()));
+        }
+        if let Some(content_md5) = &build.content_md5 {
+            headers.push(("content-md5".to_string(), content_md5.trim().to_string()));
+        }
+        headers.sort_by(|a, b| a.0.cmp(&b.0));
+        let canonical_headers = headers
+            .iter()
+            .map(|(k, v)| format!("{}:{}\n", k, v))
+            .collect::<String>();
+        let payload = build
+            .oss_headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("x-oss-content-sha256"))
+            .map(|(_, v)| v.as_str())
+            .unwrap_or(V4_UNSIGNED_PAYLOAD);
+        format!(
+            "{}\n{}\n{}\n{}\n\n{}",
+            build.method,
+            canonical_uri,
+            build.query_string(),
+            canonical_headers,
+            payload,
+        )
+    }
+}
+
+pub(crate) fn v4_scope(now: &DateTime<Utc>, region: &str) -> String {
+    format!("{}/{}/oss/aliyun_v4_request", now.format("%Y%m%d"), region)
+}
+
+pub(crate) fn v4_signing_key(key_secret: &str, now: &DateTime<Utc>, region: &str) -> Vec<u8> {
+    let date_key = hmac_sha256(format!("aliyun_v4{}", key_secret).as_bytes(), now.format("%Y%m%d").to_string().as_bytes());
+    let date_region_key = hmac_sha256(&date_key, region.as_bytes());
+    let date_region_service_key = hmac_sha256(&date_region_key, b"oss");
+    hmac_sha256(&date_region_service_key, b"aliyun_v4_request")
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut hasher: Hmac<Sha256> = Hmac::new_from_slice(key).unwrap();
+    hasher.update(data);
+    hasher.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose;
+    use hmac::{Hmac, Mac};
+    use reqwest::header::DATE;
+    use crate::auth::AuthAPI;
+    use crate::oss::OSS;
+    use crate::request::{RequestBuilder, RequestType};
+
+    #[test]
+    fn test_sign_canonicalized_oss_headers() {
+        let oss = OSS::new("id", "secret", "oss-cn-shanghai.aliyuncs.com", "dest");
+        let mut build = RequestBuilder::new()
+            .oss_header_put("X-OSS-Meta-A", "1")
+            .oss_header_put("x-oss-copy-source", "/src/a.txt");
+        build.method = RequestType::Put;
+        build.headers.insert(DATE.to_string(), "Wed, 28 Dec 2022 10:27:41 GMT".to_string());
+        let sign_str = "PUT\n\n\nWed, 28 Dec 2022 10:27:41 GMT\nx-oss-copy-source:/src/a.txt\nx-oss-meta-a:1\n/dest/b.txt";
+        let mut hasher: Hmac<sha1::Sha1> = Hmac::new_from_slice(b"secret").unwrap();
+        hasher.update(sign_str.as_bytes());
+        let expected = general_purpose::STANDARD.encode(hasher.finalize().into_bytes());
+        assert_eq!(oss.sign("/b.txt", &build), expected);
+    }
+
+    #[test]
+    fn test_sign_only_sub_resources() {
+        let oss = OSS::new("id", "secret", "oss-cn-shanghai.aliyuncs.com", "");
+        let mut build = RequestBuilder::new().parameters_put("acl", "");
+        build.headers.insert(DATE.to_string(), "Wed, 28 Dec 2022 10:27:41 GMT".to_string());
+        let with_query = build.clone()
+            .parameters_put("prefix", "tenant-")
+            .parameters_put("max-keys", "10");
+        assert_eq!(oss.sign("/", &build), oss.sign("/", &with_query));
+    }
+
+    #[test]
+    fn test_canonical_request_v4() {
+        let oss = OSS::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com", "examplebucket");
+        let mut build = RequestBuilder::new()
+            .with_content_type("text/plain")
+            .oss_header_put("x-oss-meta-Owner", " lake ")
+            .oss_header_put("x-oss-content-sha256", "UNSIGNED-PAYLOAD")
+            .parameters_put("acl", "");
+        build.method = RequestType::Put;
+        assert_eq!(
+            oss.canonical_request_v4("/exampleobject 1.txt", &build),
+            "PUT\n/examplebucket/exampleobject%201.txt\nacl\ncontent-type:text/plain\nx-oss-content-sha256:UNSIGNED-PAYLOAD\nx-oss-meta-owner:lake\n\n\nUNSIGNED-PAYLOAD"
+        );
+    }
+
+    #[test]
+    fn test_oss_sign_v4_requires_region() {
+        let oss = OSS::new("id", "secret", "127.0.0.1:9000", "examplebucket");
+        let build = RequestBuilder::new();
+        assert!(oss.oss_sign_v4("/a.txt", &build, chrono::Utc::now()).is_err());
+        let oss = OSS::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com", "examplebucket");
+        let now = chrono::DateTime::parse_from_rfc3339("2023-12-03T12:12:12Z").unwrap().with_timezone(&chrono::Utc);
+        let authorization = oss.oss_sign_v4("/a.txt", &build, now).unwrap();
+        assert!(authorization.starts_with("OSS4-HMAC-SHA256 Credential=id/20231203/cn-hangzhou/oss/aliyun_v4_request,Signature="));
+    }
 }
@@ -0,0 +1,85 @@
+use crate::acl::{AccessControlPolicy, Acl};
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 设置bucket的访问权限
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::acl::Acl;
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.put_bucket_acl(Acl::PublicRead, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_bucket_acl(&self, acl: Acl, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build
+            .parameters_put("acl", "")
+            .oss_header_put("x-oss-acl", acl.to_string().as_str());
+        build.method = RequestType::Put;
+        self.send_request("/", build, None)?;
+        Ok(())
+    }
+
+    /// 获取bucket的访问权限
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let acl = oss.get_bucket_acl(RequestBuilder::new()).unwrap();
+    /// println!("acl: {}", acl);
+    /// ```
+    pub fn get_bucket_acl(&self, build: RequestBuilder) -> Result<Acl, OssError> {
+        let mut build = build.parameters_put("acl", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let policy: AccessControlPolicy = quick_xml::de::from_str(&text)?;
+        Ok(policy.access_control_list.grant)
+    }
+
+    /// 设置文件的访问权限
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::acl::Acl;
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.put_object_acl("/hello.txt", Acl::PublicRead, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_object_acl<S: AsRef<str>>(
+        &self,
+        key: S,
+        acl: Acl,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("acl", "").with_object_acl(acl);
+        build.method = RequestType::Put;
+        self.send_request(key, build, None)?;
+        Ok(())
+    }
+
+    /// 获取文件的访问权限，`Acl::Default`表示继承bucket的权限
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let acl = oss.get_object_acl("/hello.txt", RequestBuilder::new()).unwrap();
+    /// println!("acl: {}", acl);
+    /// ```
+    pub fn get_object_acl<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+    ) -> Result<Acl, OssError> {
+        let mut build = build.parameters_put("acl", "");
+        build.method = RequestType::Get;
+        let response = self.send_request(key, build, None)?;
+        let text = response.text()?;
+        let policy: AccessControlPolicy = quick_xml::de::from_str(&text)?;
+        Ok(policy.access_control_list.grant)
+    }
+}
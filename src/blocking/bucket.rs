@@ -0,0 +1,148 @@
+use crate::bucket::{BucketInfo, BucketInfoResult, BucketStat, CreateBucketConfiguration, ListBucketsBuilder, ListBucketsResult, LocationConstraint};
+use crate::client::Client;
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::region::Region;
+use crate::request::{RequestBuilder, RequestType};
+
+impl Client {
+    /// 列举bucket(分页)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::bucket::ListBucketsBuilder;
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let client = Client::from_env();
+    /// let mut query = Some(ListBucketsBuilder::new().with_prefix("tenant-").with_max_keys(100));
+    /// while let Some(q) = query {
+    ///     let result = client.list_buckets(q.clone(), RequestBuilder::new()).unwrap();
+    ///     result.buckets.iter().for_each(|b| println!("{} {}", b.name, b.location));
+    ///     query = result.next_page(&q);
+    /// }
+    /// ```
+    pub fn list_buckets(
+        &self,
+        query: ListBucketsBuilder,
+        build: RequestBuilder,
+    ) -> Result<ListBucketsResult, OssError> {
+        let mut build = query.apply(build);
+        build.method = RequestType::Get;
+        let response = self.bucket("").send_request("/", build, None)?;
+        let text = response.text()?;
+        Ok(quick_xml::de::from_str(&text)?)
+    }
+
+    /// 查询bucket所在地域，返回使用该地域endpoint的bucket句柄(保留当前的endpoint类型)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let client = Client::from_env();
+    /// let oss = client.discover_bucket("my-bucket", RequestBuilder::new()).unwrap();
+    /// let bytes = oss.get_object("/hello.txt", RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn discover_bucket<S: AsRef<str>>(
+        &self,
+        bucket: S,
+        build: RequestBuilder,
+    ) -> Result<OSS, OssError> {
+        let region = self.bucket(bucket.as_ref()).get_bucket_location(build)?;
+        Ok(self.for_region(region).bucket(bucket.as_ref()))
+    }
+}
+
+impl OSS {
+    /// 创建bucket
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::acl::Acl;
+    /// use aliyun_oss_rust_sdk::bucket::{CreateBucketConfiguration, DataRedundancyType, StorageClass};
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let client = Client::from_env();
+    /// let config = CreateBucketConfiguration::new()
+    ///     .with_storage_class(StorageClass::Standard)
+    ///     .with_data_redundancy_type(DataRedundancyType::ZRS)
+    ///     .with_acl(Acl::Private);
+    /// client.bucket("tenant-a").create_bucket(config, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn create_bucket(
+        &self,
+        config: CreateBucketConfiguration,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = config.apply_headers(build);
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&config)?;
+        self.send_request("/", build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 删除bucket，bucket中不能有文件和未完成的分片上传
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let client = Client::from_env();
+    /// client.bucket("tenant-a").delete_bucket(RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn delete_bucket(&self, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build;
+        build.method = RequestType::Delete;
+        self.send_request("/", build, None)?;
+        Ok(())
+    }
+
+    /// 获取bucket详细信息(地域、创建时间、存储类型、版本控制和加密状态等)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let info = oss.get_bucket_info(RequestBuilder::new()).unwrap();
+    /// println!("{} {} {:?}", info.name, info.location, info.versioning);
+    /// ```
+    pub fn get_bucket_info(&self, build: RequestBuilder) -> Result<BucketInfo, OssError> {
+        let mut build = build.parameters_put("bucketInfo", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let result: BucketInfoResult = quick_xml::de::from_str(&text)?;
+        Ok(result.bucket)
+    }
+
+    /// 获取bucket所在地域
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let region = oss.get_bucket_location(RequestBuilder::new()).unwrap();
+    /// println!("region: {}", region);
+    /// ```
+    pub fn get_bucket_location(&self, build: RequestBuilder) -> Result<Region, OssError> {
+        let mut build = build.parameters_put("location", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let result: LocationConstraint = quick_xml::de::from_str(&text)?;
+        Ok(Region::new(result.location))
+    }
+
+    /// 获取bucket存储容量、文件数量和分片上传数量等统计信息(非实时)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let stat = oss.get_bucket_stat(RequestBuilder::new()).unwrap();
+    /// println!("storage: {} objects: {}", stat.storage, stat.object_count);
+    /// ```
+    pub fn get_bucket_stat(&self, build: RequestBuilder) -> Result<BucketStat, OssError> {
+        let mut build = build.parameters_put("stat", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        Ok(quick_xml::de::from_str(&text)?)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::callback::{CallbackRequest, CallbackVerifier, PublicKeyFetcher};
+use crate::client::Client;
+use crate::error::OssError;
+
+impl PublicKeyFetcher for Client {
+    fn fetch(&self, url: &str) -> Result<String, OssError> {
+        let response = self.http().get(url).send()?.error_for_status()?;
+        Ok(response.text()?)
+    }
+}
+
+impl CallbackVerifier {
+    /// 验证OSS回调请求的签名，公钥不在缓存中时通过`fetcher`获取
+    pub fn verify<F: PublicKeyFetcher>(
+        &self,
+        request: &CallbackRequest,
+        body: &[u8],
+        fetcher: &F,
+    ) -> Result<(), OssError> {
+        let url = self.key_url(request)?;
+        let key = match self.cached_key(&url) {
+            Some(key) => key,
+            None => {
+                let pem = fetcher.fetch(&url)?;
+                self.cache_key(&url, &pem)?
+            }
+        };
+        Self::verify_with_key(request, body, &key)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::crc64::crc64;
+use crate::encryption::{content_offset, ContentCipher, CryptoOSS};
+use crate::error::OssError;
+use crate::request::{RequestBuilder, RequestType};
+use crate::util::read_file;
+
+impl CryptoOSS {
+    /// 加密后上传文件(内存)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::encryption::{CryptoOSS, RsaMasterKey};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let master_key = RsaMasterKey::from_pem(
+    ///     std::fs::read_to_string("rsa_public.pem").unwrap(),
+    ///     std::fs::read_to_string("rsa_private.pem").unwrap(),
+    /// ).unwrap();
+    /// let crypto = CryptoOSS::new(OSS::from_env(), master_key);
+    /// crypto.put_object_from_buffer("/tenant/a.txt", "secret".as_bytes(), RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_object_from_buffer<S: AsRef<str>>(
+        &self,
+        key: S,
+        buffer: &[u8],
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let cipher = ContentCipher::generate();
+        let mut build = build;
+        build.method = RequestType::Put;
+        build.oss_headers.extend(cipher.headers(self.master_key(), buffer.len())?);
+        let mut body = buffer.to_vec();
+        cipher.apply(&mut body, 0);
+        let crc = crc64(&body);
+        let response = self.oss().send_request(key, build, Some(body))?;
+        self.oss().verify_crc64(response.headers(), crc)?;
+        Ok(())
+    }
+
+    /// 加密后上传文件(本地文件)
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::encryption::{CryptoOSS, RsaMasterKey};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let master_key = RsaMasterKey::from_pem(
+    ///     std::fs::read_to_string("rsa_public.pem").unwrap(),
+    ///     std::fs::read_to_string("rsa_private.pem").unwrap(),
+    /// ).unwrap();
+    /// let crypto = CryptoOSS::new(OSS::from_env(), master_key);
+    /// crypto.put_object_from_file("/tenant/report.pdf", "./report.pdf", RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_object_from_file<S: AsRef<str>>(
+        &self,
+        key: S,
+        file_path: S,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let buffer = read_file(file_path)?;
+        self.put_object_from_buffer(key, buffer.as_slice(), build)
+    }
+
+    /// 下载并解密文件，支持`with_range`指定范围(按响应的`Content-Range`计算解密偏移)，未加密的文件原样返回
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::encryption::{CryptoOSS, RsaMasterKey};
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let master_key = RsaMasterKey::from_pem(
+    ///     std::fs::read_to_string("rsa_public.pem").unwrap(),
+    ///     std::fs::read_to_string("rsa_private.pem").unwrap(),
+    /// ).unwrap();
+    /// let crypto = CryptoOSS::new(OSS::from_env(), master_key);
+    /// let bytes = crypto.get_object("/tenant/a.txt", RequestBuilder::new().with_range(2, None)).unwrap();
+    /// ```
+    pub fn get_object<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+    ) -> Result<Vec<u8>, OssError> {
+        let mut build = build;
+        build.method = RequestType::Get;
+        let response = self.oss().send_request(key, build, None)?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let offset = content_offset(status, &headers)?;
+        let cipher = ContentCipher::from_headers(&headers, self.master_key())?;
+        let mut result = response.bytes()?.to_vec();
+        if offset.is_none() {
+            self.oss().verify_crc64(&headers, crc64(&result))?;
+        }
+        if let Some(cipher) = cipher {
+            cipher.apply(&mut result, offset.unwrap_or(0));
+        }
+        Ok(result)
+    }
+}
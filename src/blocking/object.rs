@@ -1,10 +1,10 @@
-use hmac::Hmac;
-use sha1::digest::Mac;
+use crate::crc64::crc64;
+use crate::auth::SignVersion;
 use crate::entity::{PolicyBuilder, PolicyResp};
 use crate::error::OssError;
-use crate::oss::{API, OSS, OSSInfo};
+use crate::policy::PostPolicy;
+use crate::oss::{OSS, OSSInfo};
 use crate::request::{RequestBuilder, RequestType};
-use crate::{debug, util};
 use crate::util::read_file;
 use crate::metadata::*;
 
@@ -22,22 +22,17 @@ impl OSS {
     /// println!("file content: {}", String::from_utf8_lossy(bytes.as_slice()));
     /// ```
     pub fn get_object<S: AsRef<str>>(&self, key: S, build: RequestBuilder) -> Result<Vec<u8>, OssError> {
-        let key = self.format_key(key);
-        let (url, headers) = self.build_request(key.as_str(), build)
-            .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
-        debug!("get object url: {} headers: {:?}", url, headers);
-        let client = reqwest::blocking::Client::new();
-        let response = client.get(url)
-            .headers(headers).send()?;
-        return if response.status().is_success() {
-            let result = response.bytes()?;
-            Ok(result.to_vec())
-        } else {
-            let status = response.status();
-            let result = response.text()?;
-            debug!("get object status: {} error: {}", status,result);
-            Err(OssError::Err(format!("get object status: {} error: {}", status, result)))
-        };
+        let mut build = build;
+        build.method = RequestType::Get;
+        //范围下载和图片处理返回的不是完整文件，不校验CRC64
+        let partial = build.has_header("Range") || build.parameters.contains_key("x-oss-process");
+        let response = self.send_request(key, build, None)?;
+        let headers = response.headers().clone();
+        let result = response.bytes()?;
+        if !partial {
+            self.verify_crc64(&headers, crc64(&result))?;
+        }
+        Ok(result.to_vec())
     }
 
     /// 获取上传对象的policy
@@ -54,52 +49,38 @@ impl OSS {
     /// let policy = oss.get_upload_object_policy(policy_builder).unwrap();
     /// println!("policy: {:?}", policy);
     /// //使用postman测试上传
-    /// //form-data的参数为OSSAccessKeyId、policy、signature、success_action_status、key、file
+    /// //form-data的参数为OSSAccessKeyId、policy、Signature、success_action_status、key、Content-Type、file
     /// //key为上传的文件名包含路径、例如：upload/mydir/test.txt
     /// //file为上传的文件，类型跟with_content_type一致
     /// ```
     pub fn get_upload_object_policy(&self, build: PolicyBuilder) -> Result<PolicyResp, OssError> {
-        let date = chrono::Local::now().naive_local() + chrono::Duration::try_seconds(build.expire).unwrap();
-        let date_str = date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
-        let mut json_data = r#"
-        {
-            "expiration": "{time}",
-            "conditions": [
-                {"bucket": "{bucket}" },
-                ["content-length-range", 1, {size}],
-                ["eq", "$success_action_status", "{success_action_status}"],
-                ["starts-with", "$key", "{prefix}"],
-                ["in", "$content-type", ["{content_type}"]]
-            ]
-        }
-        "#.to_string();
         let success_action_status = 200;
-        json_data = json_data.replacen("{time}", &date_str, 1);
-        json_data = json_data.replacen("{bucket}", &self.bucket(), 1);
-        //limit 1GB bytes
-        json_data = json_data.replacen("{size}", &build.max_upload_size.to_string(), 1);//允许上传的最大文件大小
-        //success status
-        json_data = json_data.replacen("{success_action_status}", success_action_status.to_string().as_str(), 1);
-        json_data = json_data.replacen("{prefix}", &build.upload_dir, 1);//只允许上传到哪个目录上
-        //text file
-        json_data = json_data.replacen("{content_type}", &build.content_type, 1);
-        //只允许上传哪个类型文件
-        debug!("policy json: {}", json_data);
-        let base64_policy = util::base64_encode(json_data.as_bytes());
-        let mut hasher: Hmac<sha1::Sha1> = Hmac::new_from_slice(self.key_secret().as_bytes())
-            .map_err(|_| OssError::Err("Hmac new from slice error".to_string()))?;
-        hasher.update(base64_policy.as_bytes());
-        let signature = util::base64_encode(&hasher.finalize().into_bytes());
+        let policy = PostPolicy::new()
+            .with_expire(build.expire)
+            .with_sign_version(SignVersion::V1)
+            .with_content_length_range(1, build.max_upload_size.max(1) as u64)//允许上传的最大文件大小
+            .with_success_action_status(success_action_status as u16)
+            .with_key_prefix(&build.upload_dir)//只允许上传到哪个目录上
+            .with_in("content-type", [&build.content_type]);//只允许上传哪个类型文件
+        let policy = match &build.callback {
+            Some(callback) => policy.with_callback(callback),
+            None => policy,
+        };
+        let mut form = self.sign_post_policy(&policy)?;
+        let fields = &mut form.fields;
+        let mut field = |name: &str| fields.remove(name).unwrap_or_default();
         Ok(PolicyResp {
-            access_id: self.key_id().to_string(),
-            host: format!("https://{}.{}", self.bucket(), self.endpoint()),
-            policy: base64_policy,
-            signature,
+            access_id: field("OSSAccessKeyId"),
+            policy: field("policy"),
+            signature: field("Signature"),
+            callback: fields.remove("callback"),
+            callback_vars: fields.iter().filter(|(k, _)| k.starts_with("x:")).map(|(k, v)| (k.clone(), v.clone())).collect(),
+            host: form.host,
             success_action_status,
         })
     }
 
-    /// 上传文件(本地文件)
+    /// 上传文件(本地文件)，设置了上传回调时返回回调服务器的响应内容，否则返回空
     /// # 使用例子
     /// ```rust
     /// use aliyun_oss_rust_sdk::oss::OSS;
@@ -110,30 +91,18 @@ impl OSS {
     /// let file_path = "./hello.txt";
     /// oss.put_object_from_file("/hello.txt", file_path, builder).unwrap();
     /// ```
-    pub fn put_object_from_file<S: AsRef<str>>(&self, key: S, file_path: S, build: RequestBuilder) -> Result<(), OssError> {
-        let buffer = read_file(file_path)?;
-        let mut build = build;
+    pub fn put_object_from_file<S: AsRef<str>>(&self, key: S, file_path: S, build: RequestBuilder) -> Result<Vec<u8>, OssError> {
+        let buffer = read_file(file_path.as_ref())?;
+        let names = [key.as_ref(), file_path.as_ref()];
+        let mut build = self.resolve_content_type(&names, Some(&buffer), build);
         build.method = RequestType::Put;
-        let key = self.format_key(key);
-        let (url, headers) = self.build_request(key.as_str(), build)
-            .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
-        debug!("put object from file: {} headers: {:?}", url,headers);
-        let client = reqwest::blocking::Client::new();
-        let response = client.put(url)
-            .headers(headers)
-            .body(buffer)
-            .send()?;
-        return if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status();
-            let result = response.text()?;
-            debug!("get object status: {} error: {}", status,result);
-            Err(OssError::Err(format!("get object status: {} error: {}", status, result)))
-        };
+        let crc = crc64(&buffer);
+        let response = self.send_request(key, build, Some(buffer))?;
+        self.verify_crc64(response.headers(), crc)?;
+        Ok(response.bytes()?.to_vec())
     }
 
-    /// 上传文件(内存)
+    /// 上传文件(内存)，设置了上传回调时返回回调服务器的响应内容，否则返回空
     /// # 使用例子
     /// ```rust
     /// use aliyun_oss_rust_sdk::oss::OSS;
@@ -145,26 +114,12 @@ impl OSS {
     /// let buffer = std::fs::read(file_path).unwrap();
     /// oss.pub_object_from_buffer("/hello.txt", buffer.as_slice(), builder).unwrap();
     /// ```
-    pub fn pub_object_from_buffer<S: AsRef<str>>(&self, key: S, buffer: &[u8], build: RequestBuilder) -> Result<(), OssError> {
-        let mut build = build;
+    pub fn pub_object_from_buffer<S: AsRef<str>>(&self, key: S, buffer: &[u8], build: RequestBuilder) -> Result<Vec<u8>, OssError> {
+        let mut build = self.resolve_content_type(&[key.as_ref()], Some(buffer), build);
         build.method = RequestType::Put;
-        let key = self.format_key(key);
-        let (url, headers) = self.build_request(key.as_str(), build)
-            .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
-        debug!("put object from file: {} headers: {:?}", url,headers);
-        let client = reqwest::blocking::Client::new();
-        let response = client.put(url)
-            .headers(headers)
-            .body(buffer.to_owned())
-            .send()?;
-        return if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status();
-            let result = response.text()?;
-            debug!("get object status: {} error: {}", status,result);
-            Err(OssError::Err(format!("get object status: {} error: {}", status, result)))
-        };
+        let response = self.send_request(key, build, Some(buffer.to_owned()))?;
+        self.verify_crc64(response.headers(), crc64(buffer))?;
+        Ok(response.bytes()?.to_vec())
     }
 
     /// 删除文件
@@ -178,24 +133,10 @@ impl OSS {
     /// oss.delete_object("/hello.txt", builder).unwrap();
     /// ```
     pub fn delete_object<S: AsRef<str>>(&self, key: S, build: RequestBuilder) -> Result<(), OssError> {
-        let mut build = build.clone();
+        let mut build = build;
         build.method = RequestType::Delete;
-        let key = self.format_key(key);
-        let (url, headers) = self.build_request(key.as_str(), build)
-            .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
-        debug!("put object from file: {} headers: {:?}", url,headers);
-        let client = reqwest::blocking::Client::new();
-        let response = client.delete(url)
-            .headers(headers)
-            .send()?;
-        return if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status();
-            let result = response.text()?;
-            debug!("get object status: {} error: {}", status,result);
-            Err(OssError::Err(format!("get object status: {} error: {}", status, result)))
-        };
+        self.send_request(key, build, None)?;
+        Ok(())
     }
 
     /// 获取对象元数据
@@ -210,25 +151,51 @@ impl OSS {
     /// println!("{:?}", metadata);
     /// ```
     pub fn get_object_metadata<S: AsRef<str>>(&self, key: S, build: RequestBuilder) -> Result<ObjectMetadata, OssError>{
-        let mut build = build.clone();
+        let mut build = build;
         build.method = RequestType::Head;
-        let key = self.format_key(key);
-        let (url, headers) = self.build_request(key.as_str(), build)
-            .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
-        debug!("put object from file: {} headers: {:?}", url,headers);
-        let client = reqwest::blocking::Client::new();
-        let response = client.head(url)
-            .headers(headers)
-            .send()?;
-        return if response.status().is_success() {
-            let metadata = ObjectMetadata::new(response.headers());
-            Ok(metadata)
-        } else {
-            let status = response.status();
-            let result = response.text()?;
-            debug!("get object status: {} error: {}", status,result);
-            Err(OssError::Err(format!("get object status: {} error: {}", status, result)))
-        };
+        let response = self.send_request(key, build, None)?;
+        Ok(ObjectMetadata::new(response.headers()))
+    }
+
+    /// 复制同一bucket内的文件
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let builder = RequestBuilder::new();
+    /// oss.copy_object("/hello.txt", "/hello_copy.txt", builder).unwrap();
+    /// ```
+    pub fn copy_object<S: AsRef<str>>(&self, source_key: S, key: S, build: RequestBuilder) -> Result<(), OssError> {
+        self.copy_object_from(self.bucket(), source_key, key, build)
+    }
+
+    /// 从其它bucket复制文件到当前bucket(两个bucket需在同一地域)
+    ///
+    /// `build`中通过`with_version_id`指定的版本作为源文件的版本，
+    /// 通过`with_tags`设置标签时使用新标签替换源文件的标签
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let client = Client::from_env();
+    /// let backup = client.bucket("my-backup");
+    /// let builder = RequestBuilder::new();
+    /// backup.copy_object_from("my-bucket", "/hello.txt", "/hello.txt", builder).unwrap();
+    /// ```
+    pub fn copy_object_from<B: AsRef<str>, S: AsRef<str>>(&self, source_bucket: B, source_key: S, key: S, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build;
+        let mut copy_source = self.copy_source(source_bucket, source_key);
+        if let Some(version_id) = build.parameters.remove("versionId") {
+            copy_source = format!("{}?versionId={}", copy_source, version_id);
+        }
+        if build.oss_headers.contains_key("x-oss-tagging") && !build.oss_headers.contains_key("x-oss-tagging-directive") {
+            build.oss_headers.insert("x-oss-tagging-directive".to_string(), "Replace".to_string());
+        }
+        let mut build = build.oss_header_put("x-oss-copy-source", copy_source.as_str());
+        build.method = RequestType::Put;
+        self.send_request(key, build, None)?;
+        Ok(())
     }
 }
 
@@ -258,7 +225,7 @@ mod tests {
         let policy = oss.get_upload_object_policy(policy_builder).unwrap();
         println!("policy: {:?}", policy);
         //使用postman测试上传
-        //form-data的参数为OSSAccessKeyId、policy、signature、success_action_status、key、file
+        //form-data的参数为OSSAccessKeyId、policy、Signature、success_action_status、key、Content-Type、file
         //key为上传的文件名包含路径、例如：upload/mydir/test.txt
         //file为上传的文件，类型跟with_content_type一致
     }
@@ -0,0 +1,95 @@
+use reqwest::blocking::multipart::{Form, Part};
+use reqwest::StatusCode;
+use crate::crc64::crc64;
+use crate::debug;
+use crate::error::OssError;
+use crate::oss::{API, OSS};
+use crate::policy::{PostObjectResult, PostPolicy, PostPolicyForm};
+
+impl OSS {
+    /// 使用PostObject表单上传，`form_fields`为policy之外浏览器需要填写的字段，例如`Content-Type`，
+    /// 设置了上传回调时结果中的`body`为回调服务器的响应内容
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::policy::PostPolicy;
+    /// let oss = OSS::from_env();
+    /// let policy = PostPolicy::new()
+    ///     .with_key_prefix("upload/")
+    ///     .with_in("content-type", ["text/plain"]);
+    /// let result = oss.post_object("/upload/hello.txt", "hello".as_bytes(), &policy, [("Content-Type", "text/plain")]).unwrap();
+    /// println!("etag: {:?}", result.etag);
+    /// ```
+    pub fn post_object<S, I, K, V>(
+        &self,
+        key: S,
+        body: &[u8],
+        policy: &PostPolicy,
+        form_fields: I,
+    ) -> Result<PostObjectResult, OssError>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let form = self.sign_post_policy(policy)?;
+        self.post_object_with_form(key, body, &form, form_fields)
+    }
+
+    /// 使用已签名的PostObject表单上传，不需要AccessKeySecret
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::policy::PostPolicyForm;
+    /// let oss = OSS::new("", "", "oss-cn-hangzhou.aliyuncs.com", "my-bucket");
+    /// let form: PostPolicyForm = fetch_form_from_server();
+    /// oss.post_object_with_form("/upload/hello.txt", "hello".as_bytes(), &form, [("Content-Type", "text/plain")]).unwrap();
+    /// ```
+    pub fn post_object_with_form<S, I, K, V>(
+        &self,
+        key: S,
+        body: &[u8],
+        form: &PostPolicyForm,
+        form_fields: I,
+    ) -> Result<PostObjectResult, OssError>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let key = self.format_key(key);
+        let key = key.trim_start_matches('/').to_string();
+        let file_name = key.rsplit('/').next().unwrap_or_default().to_string();
+        let mut multipart = Form::new().text("key", key.clone());
+        for (name, value) in form.fields.iter().filter(|(name, _)| name.as_str() != "key") {
+            multipart = multipart.text(name.clone(), value.clone());
+        }
+        for (name, value) in form_fields {
+            multipart = multipart.text(name.as_ref().to_string(), value.as_ref().to_string());
+        }
+        //file必须是最后一个字段
+        multipart = multipart.part("file", Part::bytes(body.to_vec()).file_name(file_name));
+        let host = format!("{}/", form.host);
+        debug!("oss log: POST url: {} key: {}", host, key);
+        let response = self.client().http().post(host).multipart(multipart).send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let result = response.bytes()?.to_vec();
+        if status == StatusCode::NON_AUTHORITATIVE_INFORMATION {
+            //上传成功但回调失败
+            return Err(OssError::CallbackFailed(String::from_utf8_lossy(&result).to_string()));
+        }
+        if !status.is_success() {
+            let result = String::from_utf8_lossy(&result);
+            debug!("oss log: POST {} status: {} error: {}", key, status, result);
+            return Err(OssError::Err(format!(
+                "POST {} status: {} error: {}",
+                key, status, result
+            )));
+        }
+        self.verify_crc64(&headers, crc64(body))?;
+        Ok(PostObjectResult::new(status.as_u16(), &headers, result))
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::referer::RefererConfiguration;
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 设置bucket的防盗链配置
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::referer::RefererConfiguration;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = RefererConfiguration::new()
+    ///     .with_allow_empty_referer(false)
+    ///     .with_referer("https://*.example.com");
+    /// oss.put_bucket_referer(config, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_bucket_referer(
+        &self,
+        config: RefererConfiguration,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("referer", "");
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&config)?;
+        self.send_request("/", build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 获取bucket的防盗链配置
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let config = oss.get_bucket_referer(RequestBuilder::new()).unwrap();
+    /// println!("allowed: {}", config.is_allowed(Some("https://www.example.com/")));
+    /// ```
+    pub fn get_bucket_referer(
+        &self,
+        build: RequestBuilder,
+    ) -> Result<RefererConfiguration, OssError> {
+        let mut build = build.parameters_put("referer", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        Ok(quick_xml::de::from_str(&text)?)
+    }
+}
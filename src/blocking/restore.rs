@@ -0,0 +1,75 @@
+use std::time::Instant;
+use chrono::{DateTime, Utc};
+use crate::debug;
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+use crate::restore::{RestoreRequest, RestoreStatus, RestoreTier, RestoreWaiter};
+
+impl OSS {
+    /// 解冻归档、冷归档、深度冷归档文件，解冻完成后`days`天内可以读取
+    ///
+    /// 归档文件不支持`tier`，传None即可
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// use aliyun_oss_rust_sdk::restore::RestoreTier;
+    /// let oss = OSS::from_env();
+    /// oss.restore_object("/archive/2019.tar", 3, Some(RestoreTier::Standard), RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn restore_object<S: AsRef<str>>(
+        &self,
+        key: S,
+        days: u32,
+        tier: Option<RestoreTier>,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("restore", "");
+        build.method = RequestType::Post;
+        let body = quick_xml::se::to_string(&RestoreRequest::new(days, tier))?;
+        self.send_request(key, build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 轮询文件元数据直到解冻完成，返回解冻后的过期时间
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// use aliyun_oss_rust_sdk::restore::{RestoreTier, RestoreWaiter};
+    /// let oss = OSS::from_env();
+    /// oss.restore_object("/archive/2019.tar", 3, None, RequestBuilder::new()).unwrap();
+    /// let expiry = oss.wait_until_restored("/archive/2019.tar", RestoreWaiter::new(), RequestBuilder::new()).unwrap();
+    /// println!("readable until {}", expiry);
+    /// let bytes = oss.get_object("/archive/2019.tar", RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn wait_until_restored<S: AsRef<str>>(
+        &self,
+        key: S,
+        waiter: RestoreWaiter,
+        build: RequestBuilder,
+    ) -> Result<DateTime<Utc>, OssError> {
+        let key = key.as_ref();
+        let start = Instant::now();
+        let mut interval = None;
+        loop {
+            let metadata = self.get_object_metadata(key, build.clone())?;
+            match metadata.restore_status() {
+                Some(RestoreStatus::Restored { expiry }) => return Ok(expiry),
+                Some(RestoreStatus::Ongoing) => {}
+                None => {
+                    return Err(OssError::Err(format!("{} has no restore status, call restore_object first", key)));
+                }
+            }
+            interval = waiter.next_interval(interval, start.elapsed());
+            match interval {
+                Some(interval) => {
+                    debug!("{} is restoring, check again after {:?}", key, interval);
+                    std::thread::sleep(interval);
+                }
+                None => return Err(OssError::Err(format!("wait for {} restored timeout", key))),
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+use crate::sse::{ServerSideEncryptionRule, Sse};
+
+impl OSS {
+    /// 设置bucket默认的服务端加密方式，上传时未指定加密方式的文件使用该配置加密
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// use aliyun_oss_rust_sdk::sse::Sse;
+    /// let oss = OSS::from_env();
+    /// oss.put_bucket_encryption(Sse::Kms { key_id: None, data_encryption: None }, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_bucket_encryption(&self, sse: Sse, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build.parameters_put("encryption", "");
+        build.method = RequestType::Put;
+        let rule = ServerSideEncryptionRule {
+            apply_server_side_encryption_by_default: sse.to_bucket_encryption(),
+        };
+        let body = quick_xml::se::to_string(&rule)?;
+        self.send_request("/", build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 获取bucket默认的服务端加密方式，未设置时返回错误
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let sse = oss.get_bucket_encryption(RequestBuilder::new()).unwrap();
+    /// println!("sse: {}", sse);
+    /// ```
+    pub fn get_bucket_encryption(&self, build: RequestBuilder) -> Result<Sse, OssError> {
+        let mut build = build.parameters_put("encryption", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let rule: ServerSideEncryptionRule = quick_xml::de::from_str(&text)?;
+        Sse::from_bucket_encryption(&rule.apply_server_side_encryption_by_default)
+    }
+
+    /// 删除bucket默认的服务端加密配置
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.delete_bucket_encryption(RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn delete_bucket_encryption(&self, build: RequestBuilder) -> Result<(), OssError> {
+        let mut build = build.parameters_put("encryption", "");
+        build.method = RequestType::Delete;
+        self.send_request("/", build, None)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::error::OssError;
+use crate::oss::{API, OSS};
+use crate::request::{RequestBuilder, RequestType};
+
+impl OSS {
+    /// 创建指向`target_key`的软链接，目标文件不需要存在，`build`中的元数据、访问权限、标签作用于软链接本身
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.put_symlink("/release/latest.tar.gz", "/release/v1.2.0.tar.gz", RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_symlink<S: AsRef<str>>(
+        &self,
+        link_key: S,
+        target_key: S,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let target = self.key_urlencode(target_key.as_ref().trim_start_matches('/'));
+        let mut build = build
+            .parameters_put("symlink", "")
+            .oss_header_put("x-oss-symlink-target", target.as_str());
+        build.method = RequestType::Put;
+        self.send_request(link_key, build, None)?;
+        Ok(())
+    }
+
+    /// 获取软链接指向的文件
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let target = oss.get_symlink("/release/latest.tar.gz", RequestBuilder::new()).unwrap();
+    /// println!("latest -> {}", target);
+    /// ```
+    pub fn get_symlink<S: AsRef<str>>(
+        &self,
+        link_key: S,
+        build: RequestBuilder,
+    ) -> Result<String, OssError> {
+        let mut build = build.parameters_put("symlink", "");
+        build.method = RequestType::Get;
+        let response = self.send_request(link_key, build, None)?;
+        let target = response
+            .headers()
+            .get("x-oss-symlink-target")
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| OssError::Err("response has no x-oss-symlink-target header".to_string()))?;
+        let target = urlencoding::decode(target).map_err(|e| OssError::Err(e.to_string()))?;
+        Ok(target.into_owned())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::entity::Tag;
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+use crate::tagging::Tagging;
+
+impl OSS {
+    /// 设置文件标签，会覆盖文件已有的全部标签，最多10个
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::entity::Tag;
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let tags = vec![Tag::new("retention", "short"), Tag::new("owner", "ops")];
+    /// oss.put_object_tagging("/hello.txt", tags, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_object_tagging<S: AsRef<str>>(
+        &self,
+        key: S,
+        tags: Vec<Tag>,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("tagging", "");
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&Tagging::new(tags))?;
+        self.send_request(key, build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 获取文件标签
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let tags = oss.get_object_tagging("/hello.txt", RequestBuilder::new()).unwrap();
+    /// for tag in tags {
+    ///     println!("{}={}", tag.key, tag.value);
+    /// }
+    /// ```
+    pub fn get_object_tagging<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+    ) -> Result<Vec<Tag>, OssError> {
+        let mut build = build.parameters_put("tagging", "");
+        build.method = RequestType::Get;
+        let response = self.send_request(key, build, None)?;
+        let text = response.text()?;
+        let tagging: Tagging = quick_xml::de::from_str(&text)?;
+        Ok(tagging.tag_set.tags)
+    }
+
+    /// 删除文件的全部标签
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.delete_object_tagging("/hello.txt", RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn delete_object_tagging<S: AsRef<str>>(
+        &self,
+        key: S,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("tagging", "");
+        build.method = RequestType::Delete;
+        self.send_request(key, build, None)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::bucket::VersioningStatus;
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::request::{RequestBuilder, RequestType};
+use crate::versioning::{ListObjectVersionsBuilder, ListObjectVersionsResult, VersioningConfiguration};
+
+impl OSS {
+    /// 开启或暂停bucket的版本控制，开启后无法关闭，只能暂停
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::bucket::VersioningStatus;
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// oss.put_bucket_versioning(VersioningStatus::Enabled, RequestBuilder::new()).unwrap();
+    /// ```
+    pub fn put_bucket_versioning(
+        &self,
+        status: VersioningStatus,
+        build: RequestBuilder,
+    ) -> Result<(), OssError> {
+        let mut build = build.parameters_put("versioning", "");
+        build.method = RequestType::Put;
+        let body = quick_xml::se::to_string(&VersioningConfiguration { status: Some(status) })?;
+        self.send_request("/", build, Some(body.into_bytes()))?;
+        Ok(())
+    }
+
+    /// 获取bucket的版本控制状态，从未开启过时返回None
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// let oss = OSS::from_env();
+    /// let status = oss.get_bucket_versioning(RequestBuilder::new()).unwrap();
+    /// println!("versioning: {:?}", status);
+    /// ```
+    pub fn get_bucket_versioning(
+        &self,
+        build: RequestBuilder,
+    ) -> Result<Option<VersioningStatus>, OssError> {
+        let mut build = build.parameters_put("versioning", "");
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let config: VersioningConfiguration = quick_xml::de::from_str(&text)?;
+        Ok(config.status)
+    }
+
+    /// 列举文件的所有版本和删除标记，结果被截断时使用`next_page`继续列举，
+    /// 或者使用`list_object_versions_pages`自动翻页
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// use aliyun_oss_rust_sdk::versioning::{ListObjectVersionsBuilder, ObjectVersionEntry};
+    /// let oss = OSS::from_env();
+    /// let mut query = Some(ListObjectVersionsBuilder::new().with_prefix("config/"));
+    /// while let Some(current) = query {
+    ///     let result = oss.list_object_versions(current.clone(), RequestBuilder::new()).unwrap();
+    ///     for entry in result.entries() {
+    ///         match entry {
+    ///             ObjectVersionEntry::Version(v) => println!("{} {} {}", v.key, v.version_id, v.last_modified),
+    ///             ObjectVersionEntry::DeleteMarker(d) => println!("{} {} deleted", d.key, d.version_id),
+    ///         }
+    ///     }
+    ///     query = result.next_page(&current);
+    /// }
+    /// ```
+    pub fn list_object_versions(
+        &self,
+        query: ListObjectVersionsBuilder,
+        build: RequestBuilder,
+    ) -> Result<ListObjectVersionsResult, OssError> {
+        let mut build = query.apply(build);
+        build.method = RequestType::Get;
+        let response = self.send_request("/", build, None)?;
+        let text = response.text()?;
+        let result: ListObjectVersionsResult = quick_xml::de::from_str(&text)?;
+        Ok(result)
+    }
+
+    /// 按页列举文件的所有版本和删除标记，自动使用`next_page`翻页，某一页请求失败后结束
+    /// # 使用例子
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
+    /// use aliyun_oss_rust_sdk::versioning::ListObjectVersionsBuilder;
+    /// let oss = OSS::from_env();
+    /// for result in oss.list_object_versions_pages(ListObjectVersionsBuilder::new().with_prefix("config/"), RequestBuilder::new()) {
+    ///     let result = result.unwrap();
+    ///     result.entries().iter().for_each(|entry| println!("{} {}", entry.key(), entry.version_id()));
+    /// }
+    /// ```
+    pub fn list_object_versions_pages(
+        &self,
+        query: ListObjectVersionsBuilder,
+        build: RequestBuilder,
+    ) -> impl Iterator<Item = Result<ListObjectVersionsResult, OssError>> + '_ {
+        let mut query = Some(query);
+        std::iter::from_fn(move || {
+            let current = query.take()?;
+            let result = self.list_object_versions(current.clone(), build.clone());
+            if let Ok(result) = &result {
+                query = result.next_page(&current);
+            }
+            Some(result)
+        })
+    }
+}
@@ -0,0 +1,460 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::acl::{AccessControlList, Acl};
+use crate::region::Region;
+use crate::request::RequestBuilder;
+
+/// 存储类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StorageClass {
+    /// 标准存储
+    Standard,
+    /// 低频访问
+    IA,
+    /// 归档存储
+    Archive,
+    /// 冷归档存储
+    ColdArchive,
+    /// 深度冷归档存储
+    DeepColdArchive,
+}
+
+impl Display for StorageClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageClass::Standard => write!(f, "Standard"),
+            StorageClass::IA => write!(f, "IA"),
+            StorageClass::Archive => write!(f, "Archive"),
+            StorageClass::ColdArchive => write!(f, "ColdArchive"),
+            StorageClass::DeepColdArchive => write!(f, "DeepColdArchive"),
+        }
+    }
+}
+
+/// 数据容灾类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataRedundancyType {
+    /// 本地冗余
+    LRS,
+    /// 同城冗余
+    ZRS,
+}
+
+/// 创建bucket的配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "CreateBucketConfiguration", rename_all = "PascalCase")]
+pub struct CreateBucketConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<StorageClass>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_redundancy_type: Option<DataRedundancyType>,
+    #[serde(skip)]
+    pub acl: Option<Acl>,
+    #[serde(skip)]
+    pub resource_group_id: Option<String>,
+}
+
+impl CreateBucketConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_storage_class(mut self, storage_class: StorageClass) -> Self {
+        self.storage_class = Some(storage_class);
+        self
+    }
+    pub fn with_data_redundancy_type(mut self, data_redundancy_type: DataRedundancyType) -> Self {
+        self.data_redundancy_type = Some(data_redundancy_type);
+        self
+    }
+    pub fn with_acl(mut self, acl: Acl) -> Self {
+        self.acl = Some(acl);
+        self
+    }
+    pub fn with_resource_group_id<S: AsRef<str>>(mut self, resource_group_id: S) -> Self {
+        self.resource_group_id = Some(resource_group_id.as_ref().to_string());
+        self
+    }
+
+    /// acl和资源组通过请求头传递
+    pub(crate) fn apply_headers(&self, build: RequestBuilder) -> RequestBuilder {
+        let mut build = build;
+        if let Some(acl) = &self.acl {
+            build = build.oss_header_put("x-oss-acl", acl.to_string().as_str());
+        }
+        if let Some(resource_group_id) = &self.resource_group_id {
+            build = build.oss_header_put("x-oss-resource-group-id", resource_group_id.as_str());
+        }
+        build
+    }
+}
+
+/// 列举bucket的查询条件
+#[derive(Debug, Clone, Default)]
+pub struct ListBucketsBuilder {
+    pub prefix: Option<String>,
+    pub marker: Option<String>,
+    pub max_keys: Option<u32>,
+    pub resource_group_id: Option<String>,
+}
+
+impl ListBucketsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_prefix<S: AsRef<str>>(mut self, prefix: S) -> Self {
+        self.prefix = Some(prefix.as_ref().to_string());
+        self
+    }
+    /// 从marker之后(按字母排序)开始返回
+    pub fn with_marker<S: AsRef<str>>(mut self, marker: S) -> Self {
+        self.marker = Some(marker.as_ref().to_string());
+        self
+    }
+    /// 最大返回数量，取值1~1000，默认100
+    pub fn with_max_keys(mut self, max_keys: u32) -> Self {
+        self.max_keys = Some(max_keys);
+        self
+    }
+    pub fn with_resource_group_id<S: AsRef<str>>(mut self, resource_group_id: S) -> Self {
+        self.resource_group_id = Some(resource_group_id.as_ref().to_string());
+        self
+    }
+
+    pub(crate) fn apply(&self, build: RequestBuilder) -> RequestBuilder {
+        let mut build = build;
+        if let Some(prefix) = &self.prefix {
+            build = build.parameters_put("prefix", prefix.as_str());
+        }
+        if let Some(marker) = &self.marker {
+            build = build.parameters_put("marker", marker.as_str());
+        }
+        if let Some(max_keys) = self.max_keys {
+            build = build.parameters_put("max-keys".to_string(), max_keys.to_string());
+        }
+        if let Some(resource_group_id) = &self.resource_group_id {
+            build = build.oss_header_put("x-oss-resource-group-id", resource_group_id.as_str());
+        }
+        build
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Owner {
+    #[serde(rename = "ID", default)]
+    pub id: String,
+    #[serde(default)]
+    pub display_name: String,
+}
+
+/// bucket概要信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BucketSummary {
+    pub name: String,
+    /// 例如`oss-cn-hangzhou`
+    #[serde(default)]
+    pub location: String,
+    /// 例如`cn-hangzhou`
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub creation_date: String,
+    #[serde(default)]
+    pub extranet_endpoint: Option<String>,
+    #[serde(default)]
+    pub intranet_endpoint: Option<String>,
+    #[serde(default)]
+    pub storage_class: Option<StorageClass>,
+    #[serde(default)]
+    pub resource_group_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Buckets {
+    #[serde(rename = "Bucket", default)]
+    bucket: Vec<BucketSummary>,
+}
+
+/// 列举bucket的结果，`is_truncated`为true时使用`next_marker`获取下一页
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "ListAllMyBucketsResult", rename_all = "PascalCase")]
+pub struct ListBucketsResult {
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub marker: Option<String>,
+    #[serde(default)]
+    pub max_keys: Option<u32>,
+    #[serde(default)]
+    pub is_truncated: bool,
+    #[serde(default)]
+    pub next_marker: Option<String>,
+    #[serde(default)]
+    pub owner: Owner,
+    #[serde(rename = "Buckets", default, with = "buckets")]
+    pub buckets: Vec<BucketSummary>,
+}
+
+impl ListBucketsResult {
+    /// 下一页的查询条件，没有更多数据时返回None
+    pub fn next_page(&self, build: &ListBucketsBuilder) -> Option<ListBucketsBuilder> {
+        if !self.is_truncated {
+            return None;
+        }
+        let marker = self.next_marker.clone()?;
+        Some(build.clone().with_marker(marker))
+    }
+}
+
+/// 版本控制状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersioningStatus {
+    Enabled,
+    Suspended,
+}
+
+/// bucket默认的服务端加密方式，未开启时`sse_algorithm`为`None`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BucketEncryption {
+    /// `AES256`、`KMS`或`SM4`，OSS返回空值或`None`时为`None`
+    #[serde(
+        rename = "SSEAlgorithm",
+        default,
+        deserialize_with = "deserialize_sse_algorithm",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sse_algorithm: Option<String>,
+    #[serde(rename = "KMSMasterKeyID", default, skip_serializing_if = "Option::is_none")]
+    pub kms_master_key_id: Option<String>,
+    #[serde(rename = "KMSDataEncryption", default, skip_serializing_if = "Option::is_none")]
+    pub kms_data_encryption: Option<String>,
+}
+
+fn deserialize_sse_algorithm<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let algorithm = Option::<String>::deserialize(deserializer)?;
+    Ok(algorithm.filter(|algorithm| !algorithm.is_empty() && algorithm != "None"))
+}
+
+/// bucket详细信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BucketInfo {
+    pub name: String,
+    /// 例如`oss-cn-hangzhou`
+    pub location: String,
+    #[serde(default)]
+    pub creation_date: String,
+    #[serde(default)]
+    pub extranet_endpoint: Option<String>,
+    #[serde(default)]
+    pub intranet_endpoint: Option<String>,
+    #[serde(default)]
+    pub storage_class: Option<StorageClass>,
+    #[serde(default)]
+    pub data_redundancy_type: Option<DataRedundancyType>,
+    #[serde(default)]
+    pub resource_group_id: Option<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub owner: Owner,
+    #[serde(default)]
+    pub access_control_list: Option<AccessControlList>,
+    /// 从未开启过版本控制时为None
+    #[serde(default)]
+    pub versioning: Option<VersioningStatus>,
+    #[serde(default)]
+    pub server_side_encryption_rule: Option<BucketEncryption>,
+    #[serde(default)]
+    pub transfer_acceleration: Option<String>,
+    #[serde(default)]
+    pub cross_region_replication: Option<String>,
+}
+
+impl BucketInfo {
+    pub fn region(&self) -> Region {
+        Region::new(&self.location)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "BucketInfo")]
+pub(crate) struct BucketInfoResult {
+    #[serde(rename = "Bucket")]
+    pub(crate) bucket: BucketInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "LocationConstraint")]
+pub(crate) struct LocationConstraint {
+    #[serde(rename = "$text", default)]
+    pub(crate) location: String,
+}
+
+/// bucket存储容量和文件数量统计，容量单位为字节
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "BucketStat", rename_all = "PascalCase", default)]
+pub struct BucketStat {
+    pub storage: u64,
+    pub object_count: u64,
+    pub multipart_upload_count: u64,
+    pub multipart_part_count: u64,
+    pub live_channel_count: u64,
+    pub delete_marker_count: u64,
+    /// 统计时间，unix时间戳(秒)
+    pub last_modified_time: i64,
+    pub standard_storage: u64,
+    pub standard_object_count: u64,
+    pub infrequent_access_storage: u64,
+    pub infrequent_access_real_storage: u64,
+    pub infrequent_access_object_count: u64,
+    pub archive_storage: u64,
+    pub archive_real_storage: u64,
+    pub archive_object_count: u64,
+    pub cold_archive_storage: u64,
+    pub cold_archive_real_storage: u64,
+    pub cold_archive_object_count: u64,
+}
+
+mod buckets {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::bucket::{BucketSummary, Buckets};
+
+    pub fn serialize<S: Serializer>(value: &[BucketSummary], serializer: S) -> Result<S::Ok, S::Error> {
+        Buckets { bucket: value.to_vec() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<BucketSummary>, D::Error> {
+        Ok(Buckets::deserialize(deserializer)?.bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::acl::Acl;
+    use crate::bucket::{BucketInfoResult, BucketStat, CreateBucketConfiguration, DataRedundancyType, ListBucketsBuilder, ListBucketsResult, LocationConstraint, StorageClass, VersioningStatus};
+    use crate::region::Region;
+
+    #[test]
+    fn test_create_bucket_configuration_xml() {
+        let config = CreateBucketConfiguration::new()
+            .with_storage_class(StorageClass::IA)
+            .with_data_redundancy_type(DataRedundancyType::ZRS);
+        assert_eq!(
+            quick_xml::se::to_string(&config).unwrap(),
+            "<CreateBucketConfiguration><StorageClass>IA</StorageClass><DataRedundancyType>ZRS</DataRedundancyType></CreateBucketConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_list_buckets_result_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListAllMyBucketsResult>
+  <Prefix>tenant-</Prefix>
+  <Marker></Marker>
+  <MaxKeys>1</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <NextMarker>tenant-a</NextMarker>
+  <Owner>
+    <ID>512**</ID>
+    <DisplayName>51264</DisplayName>
+  </Owner>
+  <Buckets>
+    <Bucket>
+      <CreationDate>2014-05-15T11:18:32.000Z</CreationDate>
+      <ExtranetEndpoint>oss-cn-hangzhou.aliyuncs.com</ExtranetEndpoint>
+      <IntranetEndpoint>oss-cn-hangzhou-internal.aliyuncs.com</IntranetEndpoint>
+      <Location>oss-cn-hangzhou</Location>
+      <Name>tenant-a</Name>
+      <Region>cn-hangzhou</Region>
+      <StorageClass>Standard</StorageClass>
+    </Bucket>
+  </Buckets>
+</ListAllMyBucketsResult>"#;
+        let result: ListBucketsResult = quick_xml::de::from_str(xml).unwrap();
+        assert!(result.is_truncated);
+        assert_eq!(result.owner.id, "512**");
+        assert_eq!(result.buckets.len(), 1);
+        assert_eq!(result.buckets[0].name, "tenant-a");
+        assert_eq!(result.buckets[0].storage_class, Some(StorageClass::Standard));
+        let next = result.next_page(&ListBucketsBuilder::new().with_prefix("tenant-")).unwrap();
+        assert_eq!(next.marker.as_deref(), Some("tenant-a"));
+        assert_eq!(next.prefix.as_deref(), Some("tenant-"));
+    }
+
+    #[test]
+    fn test_bucket_info_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<BucketInfo>
+  <Bucket>
+    <AccessMonitor>Enabled</AccessMonitor>
+    <CreationDate>2013-07-31T10:56:21.000Z</CreationDate>
+    <ExtranetEndpoint>oss-cn-hangzhou.aliyuncs.com</ExtranetEndpoint>
+    <IntranetEndpoint>oss-cn-hangzhou-internal.aliyuncs.com</IntranetEndpoint>
+    <Location>oss-cn-hangzhou</Location>
+    <StorageClass>Standard</StorageClass>
+    <TransferAcceleration>Disabled</TransferAcceleration>
+    <CrossRegionReplication>Disabled</CrossRegionReplication>
+    <DataRedundancyType>LRS</DataRedundancyType>
+    <Name>oss-example</Name>
+    <ResourceGroupId>rg-aek27tc********</ResourceGroupId>
+    <Owner>
+      <DisplayName>username</DisplayName>
+      <ID>27183473914****</ID>
+    </Owner>
+    <AccessControlList>
+      <Grant>private</Grant>
+    </AccessControlList>
+    <ServerSideEncryptionRule>
+      <SSEAlgorithm>KMS</SSEAlgorithm>
+      <KMSMasterKeyID></KMSMasterKeyID>
+      <KMSDataEncryption>SM4</KMSDataEncryption>
+    </ServerSideEncryptionRule>
+    <BucketPolicy>
+      <LogBucket>examplebucket</LogBucket>
+      <LogPrefix>log/</LogPrefix>
+    </BucketPolicy>
+    <Comment>test</Comment>
+    <Versioning>Enabled</Versioning>
+  </Bucket>
+</BucketInfo>"#;
+        let info = quick_xml::de::from_str::<BucketInfoResult>(xml).unwrap().bucket;
+        assert_eq!(info.name, "oss-example");
+        assert_eq!(info.region(), Region::new("cn-hangzhou"));
+        assert_eq!(info.data_redundancy_type, Some(DataRedundancyType::LRS));
+        assert_eq!(info.access_control_list.unwrap().grant, Acl::Private);
+        assert_eq!(info.versioning, Some(VersioningStatus::Enabled));
+        assert_eq!(info.server_side_encryption_rule.unwrap().sse_algorithm.as_deref(), Some("KMS"));
+
+        let xml = xml.replace("<SSEAlgorithm>KMS</SSEAlgorithm>", "<SSEAlgorithm>None</SSEAlgorithm>");
+        let info = quick_xml::de::from_str::<BucketInfoResult>(&xml).unwrap().bucket;
+        assert_eq!(info.server_side_encryption_rule.unwrap().sse_algorithm, None);
+    }
+
+    #[test]
+    fn test_bucket_location_and_stat_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<LocationConstraint>oss-cn-hangzhou</LocationConstraint>"#;
+        let location: LocationConstraint = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(location.location, "oss-cn-hangzhou");
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<BucketStat>
+  <Storage>1600</Storage>
+  <ObjectCount>230</ObjectCount>
+  <MultipartUploadCount>40</MultipartUploadCount>
+  <LiveChannelCount>4</LiveChannelCount>
+  <LastModifiedTime>1643341269</LastModifiedTime>
+  <StandardStorage>430</StandardStorage>
+  <StandardObjectCount>66</StandardObjectCount>
+  <ArchiveObjectCount>74</ArchiveObjectCount>
+</BucketStat>"#;
+        let stat: BucketStat = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(stat.storage, 1600);
+        assert_eq!(stat.object_count, 230);
+        assert_eq!(stat.multipart_upload_count, 40);
+        assert_eq!(stat.archive_object_count, 74);
+        assert_eq!(stat.cold_archive_storage, 0);
+    }
+}
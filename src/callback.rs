@@ -0,0 +1,536 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use serde::Serialize;
+use crate::util;
+#[cfg(feature = "callback-verify")]
+pub use verify::{verify, CallbackRequest, CallbackVerifier, PublicKeyFetcher};
+
+/// 回调请求体的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CallbackBodyType {
+    /// application/x-www-form-urlencoded
+    #[default]
+    FormUrlEncoded,
+    /// application/json
+    Json,
+}
+
+impl Display for CallbackBodyType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallbackBodyType::FormUrlEncoded => write!(f, "application/x-www-form-urlencoded"),
+            CallbackBodyType::Json => write!(f, "application/json"),
+        }
+    }
+}
+
+/// 回调请求体中可以使用的变量，由OSS在回调时替换
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackVariable {
+    Bucket,
+    Object,
+    ETag,
+    Size,
+    MimeType,
+    ImageHeight,
+    ImageWidth,
+    ImageFormat,
+    /// 自定义变量，名称不含`x:`前缀，值通过`Callback::with_var`设置
+    Custom(String),
+}
+
+impl Display for CallbackVariable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallbackVariable::Bucket => write!(f, "${{bucket}}"),
+            CallbackVariable::Object => write!(f, "${{object}}"),
+            CallbackVariable::ETag => write!(f, "${{etag}}"),
+            CallbackVariable::Size => write!(f, "${{size}}"),
+            CallbackVariable::MimeType => write!(f, "${{mimeType}}"),
+            CallbackVariable::ImageHeight => write!(f, "${{imageInfo.height}}"),
+            CallbackVariable::ImageWidth => write!(f, "${{imageInfo.width}}"),
+            CallbackVariable::ImageFormat => write!(f, "${{imageInfo.format}}"),
+            CallbackVariable::Custom(name) => write!(f, "${{x:{}}}", name.trim_start_matches("x:")),
+        }
+    }
+}
+
+/// 上传回调，上传完成后OSS向`url`发送POST请求，回调服务器的响应内容会返回给调用方
+///
+/// 请求体可以用`with_body`直接指定模板，也可以用`with_field`按`body_type`生成
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::callback::{Callback, CallbackBodyType, CallbackVariable};
+/// use aliyun_oss_rust_sdk::oss::OSS;
+/// use aliyun_oss_rust_sdk::request::RequestBuilder;
+/// let oss = OSS::from_env();
+/// let callback = Callback::new("https://example.com/oss/callback")
+///     .with_body_type(CallbackBodyType::Json)
+///     .with_field("object", CallbackVariable::Object)
+///     .with_field("size", CallbackVariable::Size)
+///     .with_field("uid", CallbackVariable::Custom("uid".to_string()))
+///     .with_var("uid", "1001");
+/// let builder = RequestBuilder::new().with_callback(&callback);
+/// let body = oss.pub_object_from_buffer("/hello.txt", "hello".as_bytes(), builder).await.unwrap();
+/// println!("callback response: {}", String::from_utf8_lossy(&body));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Callback {
+    url: String,
+    host: Option<String>,
+    sni: Option<bool>,
+    body: Option<String>,
+    body_type: CallbackBodyType,
+    fields: Vec<(String, CallbackVariable)>,
+    vars: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CallbackParam<'a> {
+    callback_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_host: Option<&'a str>,
+    #[serde(rename = "callbackSNI", skip_serializing_if = "Option::is_none")]
+    callback_sni: Option<bool>,
+    callback_body: String,
+    callback_body_type: String,
+}
+
+impl Callback {
+    /// 回调地址，多个地址用`;`分隔，OSS依次尝试直到成功
+    pub fn new<S: AsRef<str>>(url: S) -> Self {
+        Self {
+            url: url.as_ref().to_string(),
+            host: None,
+            sni: None,
+            body: None,
+            body_type: CallbackBodyType::default(),
+            fields: vec![],
+            vars: BTreeMap::new(),
+        }
+    }
+
+    /// 回调请求的Host头，默认为回调地址中的host
+    pub fn with_host<S: AsRef<str>>(mut self, host: S) -> Self {
+        self.host = Some(host.as_ref().to_string());
+        self
+    }
+
+    /// 回调地址为https时是否发送SNI
+    pub fn with_sni(mut self, sni: bool) -> Self {
+        self.sni = Some(sni);
+        self
+    }
+
+    /// 请求体模板，例如`bucket=${bucket}&object=${object}&uid=${x:uid}`，设置后忽略`with_field`
+    pub fn with_body<S: AsRef<str>>(mut self, body: S) -> Self {
+        self.body = Some(body.as_ref().to_string());
+        self
+    }
+
+    pub fn with_body_type(mut self, body_type: CallbackBodyType) -> Self {
+        self.body_type = body_type;
+        self
+    }
+
+    /// 请求体中的字段
+    pub fn with_field<S: AsRef<str>>(mut self, name: S, variable: CallbackVariable) -> Self {
+        self.fields.push((name.as_ref().to_string(), variable));
+        self
+    }
+
+    /// 自定义变量的值，名称会自动加上`x:`前缀
+    pub fn with_var<K: AsRef<str>, V: AsRef<str>>(mut self, name: K, value: V) -> Self {
+        let name = name.as_ref().trim_start_matches("x:");
+        self.vars.insert(format!("x:{}", name), value.as_ref().to_string());
+        self
+    }
+
+    /// 自定义变量，key带`x:`前缀
+    pub fn vars(&self) -> &BTreeMap<String, String> {
+        &self.vars
+    }
+
+    /// 请求体模板
+    pub fn body(&self) -> String {
+        if let Some(body) = &self.body {
+            return body.to_string();
+        }
+        match self.body_type {
+            CallbackBodyType::FormUrlEncoded => self
+                .fields
+                .iter()
+                .map(|(name, variable)| format!("{}={}", urlencoding::encode(name), variable))
+                .collect::<Vec<_>>()
+                .join("&"),
+            CallbackBodyType::Json => {
+                let fields = self
+                    .fields
+                    .iter()
+                    .map(|(name, variable)| format!("{}:{}", serde_json::Value::from(name.as_str()), variable))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("{{{}}}", fields)
+            }
+        }
+    }
+
+    /// Base64编码后的回调参数，即`x-oss-callback`头或表单中`callback`字段的值
+    pub fn encode(&self) -> String {
+        let param = CallbackParam {
+            callback_url: &self.url,
+            callback_host: self.host.as_deref(),
+            callback_sni: self.sni,
+            callback_body: self.body(),
+            callback_body_type: self.body_type.to_string(),
+        };
+        let json = serde_json::to_string(&param).unwrap_or_default();
+        util::base64_encode(json.as_bytes())
+    }
+
+    /// Base64编码后的自定义变量，即`x-oss-callback-var`头的值，没有自定义变量时为None
+    pub fn encode_vars(&self) -> Option<String> {
+        if self.vars.is_empty() {
+            return None;
+        }
+        let json = serde_json::to_string(&self.vars).unwrap_or_default();
+        Some(util::base64_encode(json.as_bytes()))
+    }
+}
+
+/// 回调请求验签
+#[cfg(feature = "callback-verify")]
+mod verify {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+    use md5::{Digest, Md5};
+    use rsa::pkcs1::DecodeRsaPublicKey;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::{Pkcs1v15Sign, RsaPublicKey};
+    use crate::error::OssError;
+    use crate::util;
+
+    /// OSS回调请求中参与验签的部分
+    ///
+    /// 可以从`http::Request`/`http::request::Parts`转换(axum等)，其它框架用`CallbackRequest::new`构造
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct CallbackRequest {
+        /// 未解码的请求路径
+        pub path: String,
+        /// 不含`?`的查询字符串
+        pub query: Option<String>,
+        /// `authorization`头
+        pub authorization: String,
+        /// `x-oss-pub-key-url`头，Base64编码的公钥地址
+        pub pub_key_url: String,
+    }
+
+    impl CallbackRequest {
+        /// `path_and_query`为请求行中的路径和查询字符串，例如actix-web的`req.uri().to_string()`
+        pub fn new<P, A, K>(path_and_query: P, authorization: A, pub_key_url: K) -> Self
+        where
+            P: AsRef<str>,
+            A: AsRef<str>,
+            K: AsRef<str>,
+        {
+            let (path, query) = match path_and_query.as_ref().split_once('?') {
+                Some((path, query)) => (path, Some(query.to_string())),
+                None => (path_and_query.as_ref(), None),
+            };
+            Self {
+                path: path.to_string(),
+                query,
+                authorization: authorization.as_ref().to_string(),
+                pub_key_url: pub_key_url.as_ref().to_string(),
+            }
+        }
+
+        pub fn from_parts(parts: &http::request::Parts) -> Result<Self, OssError> {
+            Self::from_http(&parts.uri, &parts.headers)
+        }
+
+        pub fn from_request<B>(request: &http::Request<B>) -> Result<Self, OssError> {
+            Self::from_http(request.uri(), request.headers())
+        }
+
+        fn from_http(uri: &http::Uri, headers: &http::HeaderMap) -> Result<Self, OssError> {
+            let header = |name: &str| {
+                headers
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.to_string())
+                    .ok_or_else(|| OssError::InvalidCallbackSignature(format!("missing header {}", name)))
+            };
+            Ok(Self {
+                path: uri.path().to_string(),
+                query: uri.query().map(|query| query.to_string()),
+                authorization: header("authorization")?,
+                pub_key_url: header("x-oss-pub-key-url")?,
+            })
+        }
+
+        /// 待签名字符串：url解码后的路径 + `?`查询字符串 + `\n` + 请求体
+        pub(crate) fn string_to_sign(&self, body: &[u8]) -> Result<Vec<u8>, OssError> {
+            let path = urlencoding::decode(&self.path)
+                .map_err(|e| OssError::InvalidCallbackSignature(format!("invalid path: {}", e)))?;
+            let mut data = path.into_owned().into_bytes();
+            if let Some(query) = &self.query {
+                data.push(b'?');
+                data.extend_from_slice(query.as_bytes());
+            }
+            data.push(b'\n');
+            data.extend_from_slice(body);
+            Ok(data)
+        }
+    }
+
+    /// 获取回调公钥(PEM格式)，`Client`使用自身的HTTP客户端下载
+    #[cfg(not(feature = "blocking"))]
+    pub trait PublicKeyFetcher {
+        fn fetch(&self, url: &str) -> impl std::future::Future<Output = Result<String, OssError>> + Send;
+    }
+
+    /// 获取回调公钥(PEM格式)，`Client`使用自身的HTTP客户端下载
+    #[cfg(feature = "blocking")]
+    pub trait PublicKeyFetcher {
+        fn fetch(&self, url: &str) -> Result<String, OssError>;
+    }
+
+    /// 回调请求验签，公钥按地址缓存，公钥地址的host必须在白名单内(默认只有`gosspublic.alicdn.com`)
+    ///
+    /// # 使用例子
+    ///
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::callback::{CallbackRequest, CallbackVerifier};
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// let client = Client::from_env();
+    /// let verifier = CallbackVerifier::new();
+    /// //在axum的handler中：async fn callback(request: http::Request<axum::body::Bytes>)
+    /// let callback_request = CallbackRequest::from_request(&request).unwrap();
+    /// verifier.verify(&callback_request, request.body(), &client).await.unwrap();
+    /// ```
+    #[derive(Debug)]
+    pub struct CallbackVerifier {
+        allowed_hosts: Vec<String>,
+        keys: Mutex<HashMap<String, RsaPublicKey>>,
+    }
+
+    impl Default for CallbackVerifier {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl CallbackVerifier {
+        pub fn new() -> Self {
+            Self {
+                allowed_hosts: vec!["gosspublic.alicdn.com".to_string()],
+                keys: Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// 允许的公钥地址host，例如私有部署的公钥服务
+        pub fn with_allowed_host<S: AsRef<str>>(mut self, host: S) -> Self {
+            self.allowed_hosts.push(host.as_ref().to_lowercase());
+            self
+        }
+
+        /// 解码公钥地址并检查host
+        pub(crate) fn key_url(&self, request: &CallbackRequest) -> Result<String, OssError> {
+            let url = util::base64_decode(request.pub_key_url.as_bytes())?;
+            let url = String::from_utf8(url)
+                .map_err(|_| OssError::InvalidCallbackSignature("invalid public key url".to_string()))?;
+            let rest = url
+                .strip_prefix("https://")
+                .or_else(|| url.strip_prefix("http://"))
+                .ok_or_else(|| OssError::InvalidCallbackSignature(format!("invalid public key url: {}", url)))?;
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            let host = authority.split(':').next().unwrap_or_default().to_lowercase();
+            if authority.contains('@') || !self.allowed_hosts.contains(&host) {
+                return Err(OssError::InvalidCallbackSignature(format!("public key url not allowed: {}", url)));
+            }
+            Ok(url)
+        }
+
+        pub(crate) fn cached_key(&self, url: &str) -> Option<RsaPublicKey> {
+            self.keys.lock().ok().and_then(|keys| keys.get(url).cloned())
+        }
+
+        pub(crate) fn cache_key(&self, url: &str, pem: &str) -> Result<RsaPublicKey, OssError> {
+            let key = RsaPublicKey::from_public_key_pem(pem.trim())
+                .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem.trim()))
+                .map_err(|e| OssError::InvalidCallbackSignature(format!("invalid public key: {}", e)))?;
+            if let Ok(mut keys) = self.keys.lock() {
+                keys.insert(url.to_string(), key.clone());
+            }
+            Ok(key)
+        }
+
+        /// 使用指定公钥验签
+        pub fn verify_with_key(request: &CallbackRequest, body: &[u8], key: &RsaPublicKey) -> Result<(), OssError> {
+            let signature = util::base64_decode(request.authorization.as_bytes())?;
+            let digest = Md5::digest(request.string_to_sign(body)?);
+            key.verify(Pkcs1v15Sign::new::<Md5>(), &digest, &signature)
+                .map_err(|e| OssError::InvalidCallbackSignature(e.to_string()))
+        }
+    }
+
+    fn default_verifier() -> &'static CallbackVerifier {
+        static VERIFIER: OnceLock<CallbackVerifier> = OnceLock::new();
+        VERIFIER.get_or_init(CallbackVerifier::new)
+    }
+
+    /// 使用默认的`CallbackVerifier`验签
+    #[cfg(not(feature = "blocking"))]
+    pub async fn verify<F: PublicKeyFetcher>(request: &CallbackRequest, body: &[u8], fetcher: &F) -> Result<(), OssError> {
+        default_verifier().verify(request, body, fetcher).await
+    }
+
+    /// 使用默认的`CallbackVerifier`验签
+    #[cfg(feature = "blocking")]
+    pub fn verify<F: PublicKeyFetcher>(request: &CallbackRequest, body: &[u8], fetcher: &F) -> Result<(), OssError> {
+        default_verifier().verify(request, body, fetcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose;
+    use serde_json::{json, Value};
+    use crate::callback::{Callback, CallbackBodyType, CallbackVariable};
+
+    fn decode(value: &str) -> Value {
+        serde_json::from_slice(&general_purpose::STANDARD.decode(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_callback_encode() {
+        let callback = Callback::new("https://example.com/callback")
+            .with_field("bucket", CallbackVariable::Bucket)
+            .with_field("file name", CallbackVariable::Object)
+            .with_field("uid", CallbackVariable::Custom("x:uid".to_string()))
+            .with_var("uid", "1001");
+        assert_eq!(
+            decode(&callback.encode()),
+            json!({
+                "callbackUrl": "https://example.com/callback",
+                "callbackBody": "bucket=${bucket}&file%20name=${object}&uid=${x:uid}",
+                "callbackBodyType": "application/x-www-form-urlencoded",
+            })
+        );
+        assert_eq!(decode(&callback.encode_vars().unwrap()), json!({"x:uid": "1001"}));
+
+        let callback = Callback::new("https://example.com/callback")
+            .with_host("example.com")
+            .with_sni(true)
+            .with_body_type(CallbackBodyType::Json)
+            .with_field("size", CallbackVariable::Size)
+            .with_field("width", CallbackVariable::ImageWidth);
+        assert_eq!(
+            decode(&callback.encode()),
+            json!({
+                "callbackUrl": "https://example.com/callback",
+                "callbackHost": "example.com",
+                "callbackSNI": true,
+                "callbackBody": "{\"size\":${size},\"width\":${imageInfo.width}}",
+                "callbackBodyType": "application/json",
+            })
+        );
+        assert_eq!(callback.encode_vars(), None);
+        let callback = callback.with_body("object=${object}");
+        assert_eq!(callback.body(), "object=${object}");
+    }
+
+    #[cfg(feature = "callback-verify")]
+    fn signed_request(body: &[u8]) -> (http::Request<Vec<u8>>, String) {
+        use md5::{Digest, Md5};
+        use rsa::pkcs8::{EncodePublicKey, LineEnding};
+        use rsa::{Pkcs1v15Sign, RsaPrivateKey};
+        let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let pem = private_key.to_public_key().to_public_key_pem(LineEnding::LF).unwrap();
+        let mut string_to_sign = b"/oss/call back?id=1\n".to_vec();
+        string_to_sign.extend_from_slice(body);
+        let signature = private_key
+            .sign(Pkcs1v15Sign::new::<Md5>(), &Md5::digest(&string_to_sign))
+            .unwrap();
+        let request = http::Request::builder()
+            .method("POST")
+            .uri("/oss/call%20back?id=1")
+            .header("authorization", general_purpose::STANDARD.encode(signature))
+            .header(
+                "x-oss-pub-key-url",
+                general_purpose::STANDARD.encode("https://gosspublic.alicdn.com/callback_pub_key_v1.pem"),
+            )
+            .body(body.to_vec())
+            .unwrap();
+        (request, pem)
+    }
+
+    #[cfg(feature = "callback-verify")]
+    #[test]
+    fn test_callback_verify_with_key() {
+        use crate::callback::{CallbackRequest, CallbackVerifier};
+        let body = b"bucket=bucket&object=a.txt&size=5";
+        let (request, pem) = signed_request(body);
+        let callback_request = CallbackRequest::from_request(&request).unwrap();
+        assert_eq!(
+            callback_request,
+            CallbackRequest::new("/oss/call%20back?id=1", &callback_request.authorization, &callback_request.pub_key_url)
+        );
+        let verifier = CallbackVerifier::new();
+        let url = verifier.key_url(&callback_request).unwrap();
+        assert!(verifier.cached_key(&url).is_none());
+        let key = verifier.cache_key(&url, &pem).unwrap();
+        assert!(verifier.cached_key(&url).is_some());
+        assert!(CallbackVerifier::verify_with_key(&callback_request, body, &key).is_ok());
+        assert!(CallbackVerifier::verify_with_key(&callback_request, b"bucket=bucket&object=b.txt&size=5", &key).is_err());
+
+        let key_url = |url: &str| {
+            let request = CallbackRequest::new("/", "", general_purpose::STANDARD.encode(url));
+            verifier.key_url(&request)
+        };
+        assert!(key_url("http://GossPublic.alicdn.com:80/callback_pub_key_v1.pem").is_ok());
+        assert!(key_url("https://gosspublic.alicdn.com.evil.com/key.pem").is_err());
+        assert!(key_url("https://evil.com/gosspublic.alicdn.com/key.pem").is_err());
+        assert!(key_url("https://gosspublic.alicdn.com@evil.com/key.pem").is_err());
+        assert!(key_url("ftp://gosspublic.alicdn.com/key.pem").is_err());
+        let request = CallbackRequest::new("/", "", general_purpose::STANDARD.encode("https://keys.example.com/key.pem"));
+        assert!(CallbackVerifier::new().with_allowed_host("keys.example.com").key_url(&request).is_ok());
+        assert!(CallbackRequest::from_parts(&http::Request::new(()).into_parts().0).is_err());
+    }
+
+    #[cfg(all(feature = "callback-verify", not(feature = "blocking")))]
+    #[tokio::test]
+    async fn test_callback_verify() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use crate::callback::{CallbackRequest, CallbackVerifier, PublicKeyFetcher};
+        use crate::error::OssError;
+
+        struct StaticFetcher {
+            pem: String,
+            count: AtomicUsize,
+        }
+
+        impl PublicKeyFetcher for StaticFetcher {
+            async fn fetch(&self, _url: &str) -> Result<String, OssError> {
+                self.count.fetch_add(1, Ordering::SeqCst);
+                Ok(self.pem.clone())
+            }
+        }
+
+        let body = b"{\"object\":\"a.txt\"}";
+        let (request, pem) = signed_request(body);
+        let (parts, body) = request.into_parts();
+        let callback_request = CallbackRequest::from_parts(&parts).unwrap();
+        let fetcher = StaticFetcher { pem, count: AtomicUsize::new(0) };
+        let verifier = CallbackVerifier::new();
+        verifier.verify(&callback_request, &body, &fetcher).await.unwrap();
+        verifier.verify(&callback_request, &body, &fetcher).await.unwrap();
+        assert_eq!(fetcher.count.load(Ordering::SeqCst), 1);
+        assert!(verifier.verify(&callback_request, b"{}", &fetcher).await.is_err());
+    }
+}
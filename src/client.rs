@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use crate::auth::SignVersion;
+use crate::mime::MimeResolver;
+use crate::oss::OSS;
+use crate::region::{EndpointType, Region};
+
+#[cfg(feature = "blocking")]
+pub(crate) type HttpClient = reqwest::blocking::Client;
+#[cfg(not(feature = "blocking"))]
+pub(crate) type HttpClient = reqwest::Client;
+
+#[derive(Debug)]
+struct Credentials {
+    key_id: String,
+    key_secret: String,
+}
+
+/// OSS客户端(凭证+连接+配置)
+///
+/// 凭证与HTTP连接池在所有bucket之间共享，克隆开销很小
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::client::Client;
+/// let client = Client::new("my_key_id", "my_key_secret", "oss-cn-shanghai.aliyuncs.com");
+/// let images = client.bucket("my-images");
+/// let logs = client.bucket("my-logs");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Client {
+    credentials: Arc<Credentials>,
+    endpoint: Option<String>,
+    region: Option<Region>,
+    endpoint_type: EndpointType,
+    sign_version: SignVersion,
+    crc_check: bool,
+    mime_resolver: Option<Arc<MimeResolver>>,
+    http: HttpClient,
+}
+
+impl Client {
+    /// 读取环境变量OSS_KEY_ID、OSS_KEY_SECRET、OSS_ENDPOINT，
+    /// 设置了OSS_REGION时可以省略OSS_ENDPOINT
+    pub fn from_env() -> Self {
+        let key_id = std::env::var("OSS_KEY_ID").expect("OSS_KEY_ID not found");
+        let key_secret = std::env::var("OSS_KEY_SECRET").expect("OSS_KEY_SECRET not found");
+        let region = std::env::var("OSS_REGION").ok().map(Region::new);
+        match (std::env::var("OSS_ENDPOINT"), region) {
+            (Ok(endpoint), region) => {
+                let mut client = Client::new(key_id, key_secret, endpoint);
+                client.region = region;
+                client
+            }
+            (Err(_), Some(region)) => Client::from_region(key_id, key_secret, region),
+            (Err(_), None) => panic!("OSS_ENDPOINT not found"),
+        }
+    }
+
+    pub fn new<S: Into<String>>(key_id: S, key_secret: S, endpoint: S) -> Self {
+        Client::build(key_id.into(), key_secret.into(), Some(endpoint.into()))
+    }
+
+    fn build(key_id: String, key_secret: String, endpoint: Option<String>) -> Self {
+        Client {
+            credentials: Arc::new(Credentials { key_id, key_secret }),
+            endpoint,
+            region: None,
+            endpoint_type: EndpointType::default(),
+            sign_version: SignVersion::default(),
+            crc_check: true,
+            mime_resolver: None,
+            http: HttpClient::new(),
+        }
+    }
+
+    /// 根据地域生成endpoint
+    ///
+    /// # 使用例子
+    ///
+    /// ```
+    /// use aliyun_oss_rust_sdk::client::Client;
+    /// use aliyun_oss_rust_sdk::region::{EndpointType, Region};
+    /// let client = Client::from_region("my_key_id", "my_key_secret", Region::new("cn-shanghai"))
+    ///     .with_endpoint_type(EndpointType::Internal);//ECS内网访问
+    /// assert_eq!(client.endpoint(), "oss-cn-shanghai-internal.aliyuncs.com");
+    /// ```
+    pub fn from_region<S: Into<String>>(key_id: S, key_secret: S, region: Region) -> Self {
+        Client::build(key_id.into(), key_secret.into(), None).with_region(region)
+    }
+
+    /// 设置地域，V4签名使用该地域；没有指定endpoint时按地域和`with_endpoint_type`生成endpoint
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// 按地域生成endpoint时使用的类型，指定了endpoint(`new`或`with_endpoint`)时不生效，与调用顺序无关
+    pub fn with_endpoint_type(mut self, endpoint_type: EndpointType) -> Self {
+        self.endpoint_type = endpoint_type;
+        self
+    }
+
+    /// 自定义endpoint(例如CNAME或本地模拟器)，优先于按地域生成的endpoint，与调用顺序无关
+    pub fn with_endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    pub fn with_sign_version(mut self, sign_version: SignVersion) -> Self {
+        self.sign_version = sign_version;
+        self
+    }
+
+    /// 是否校验上传下载数据的CRC64，默认开启，范围下载不校验
+    pub fn with_crc_check(mut self, crc_check: bool) -> Self {
+        self.crc_check = crc_check;
+        self
+    }
+
+    /// 上传时未设置Content-Type的请求按扩展名和文件头自动推断，默认关闭
+    pub fn with_mime_resolver(mut self, mime_resolver: MimeResolver) -> Self {
+        self.mime_resolver = Some(Arc::new(mime_resolver));
+        self
+    }
+
+    /// 切换到指定地域的endpoint，沿用指定endpoint的协议和类型，
+    /// CNAME、本地模拟器等非OSS域名的endpoint保持不变
+    pub(crate) fn for_region(&self, region: Region) -> Client {
+        let endpoint = match &self.endpoint {
+            Some(endpoint) => match EndpointType::from_endpoint(endpoint) {
+                Some(endpoint_type) => {
+                    let scheme = endpoint.find("://").map(|i| &endpoint[..i + 3]).unwrap_or_default();
+                    Some(format!("{}{}", scheme, region.endpoint(endpoint_type)))
+                }
+                None => Some(endpoint.clone()),
+            },
+            None => None,
+        };
+        let mut client = self.clone().with_region(region);
+        client.endpoint = endpoint;
+        client
+    }
+
+    /// 获取bucket句柄，共享当前客户端的凭证和连接
+    pub fn bucket<S: Into<String>>(&self, bucket: S) -> OSS {
+        OSS::from_client(self.clone(), bucket)
+    }
+
+    /// 指定的endpoint，未指定时按地域和endpoint类型生成
+    pub fn endpoint(&self) -> String {
+        match (&self.endpoint, &self.region) {
+            (Some(endpoint), _) => endpoint.clone(),
+            (None, Some(region)) => region.endpoint(self.endpoint_type),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// 当前地域，未设置时从endpoint中解析
+    pub fn region(&self) -> Option<Region> {
+        self.region.clone().or_else(|| self.endpoint.as_deref().and_then(Region::from_endpoint))
+    }
+
+    pub fn endpoint_type(&self) -> EndpointType {
+        self.endpoint_type
+    }
+
+    pub fn sign_version(&self) -> SignVersion {
+        self.sign_version
+    }
+
+    pub fn crc_check(&self) -> bool {
+        self.crc_check
+    }
+
+    pub fn mime_resolver(&self) -> Option<&MimeResolver> {
+        self.mime_resolver.as_deref()
+    }
+
+    pub fn key_id(&self) -> String {
+        self.credentials.key_id.clone()
+    }
+
+    pub fn key_secret(&self) -> String {
+        self.credentials.key_secret.clone()
+    }
+
+    pub(crate) fn http(&self) -> &HttpClient {
+        &self.http
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::oss::OSSInfo;
+    use crate::region::{EndpointType, Region};
+
+    #[test]
+    fn test_bucket_handles_share_credentials() {
+        let client = Client::new("id", "secret", "oss-cn-shanghai.aliyuncs.com");
+        let a = client.bucket("a");
+        let b = client.bucket("b");
+        assert_eq!(a.bucket(), "a");
+        assert_eq!(b.bucket(), "b");
+        assert_eq!(a.key_id(), b.key_id());
+        assert!(std::sync::Arc::ptr_eq(&a.client().credentials, &b.client().credentials));
+    }
+
+    #[test]
+    fn test_endpoint_resolution() {
+        let client = Client::from_region("id", "secret", Region::new("cn-beijing"));
+        assert_eq!(client.endpoint(), "oss-cn-beijing.aliyuncs.com");
+        let client = client.with_endpoint_type(EndpointType::Internal);
+        assert_eq!(client.endpoint(), "oss-cn-beijing-internal.aliyuncs.com");
+        assert_eq!(client.region(), Some(Region::new("cn-beijing")));
+        let client = Client::new("id", "secret", "https://oss-cn-shenzhen.aliyuncs.com");
+        assert_eq!(client.region(), Some(Region::new("cn-shenzhen")));
+    }
+
+    #[test]
+    fn test_endpoint_resolution_order() {
+        //endpoint类型可以在地域之前设置
+        let client = Client::from_region("id", "secret", Region::new("cn-beijing"))
+            .with_endpoint_type(EndpointType::Internal)
+            .with_region(Region::new("cn-hangzhou"));
+        assert_eq!(client.endpoint(), "oss-cn-hangzhou-internal.aliyuncs.com");
+
+        //指定的endpoint优先，不会被之后设置的地域或类型覆盖
+        let client = Client::from_region("id", "secret", Region::new("cn-beijing"))
+            .with_endpoint("https://oss.example.com")
+            .with_region(Region::new("cn-hangzhou"))
+            .with_endpoint_type(EndpointType::Internal);
+        assert_eq!(client.endpoint(), "https://oss.example.com");
+        assert_eq!(client.region(), Some(Region::new("cn-hangzhou")));
+
+        let client = Client::new("id", "secret", "oss-cn-shanghai.aliyuncs.com")
+            .with_endpoint_type(EndpointType::Internal);
+        assert_eq!(client.endpoint(), "oss-cn-shanghai.aliyuncs.com");
+        let client = client.for_region(Region::new("cn-beijing"));
+        assert_eq!(client.endpoint(), "oss-cn-beijing.aliyuncs.com");
+    }
+
+    #[test]
+    fn test_for_region() {
+        let client = Client::new("id", "secret", "https://oss-cn-shanghai-internal.aliyuncs.com")
+            .for_region(Region::new("cn-beijing"));
+        assert_eq!(client.endpoint(), "https://oss-cn-beijing-internal.aliyuncs.com");
+        assert_eq!(client.region(), Some(Region::new("cn-beijing")));
+
+        let client = Client::new("id", "secret", "http://127.0.0.1:9000").for_region(Region::new("cn-beijing"));
+        assert_eq!(client.endpoint(), "http://127.0.0.1:9000");
+
+        let client = Client::from_region("id", "secret", Region::new("cn-shanghai"))
+            .with_endpoint_type(EndpointType::DualStack)
+            .for_region(Region::new("cn-beijing"));
+        assert_eq!(client.endpoint(), "cn-beijing.oss.aliyuncs.com");
+    }
+}
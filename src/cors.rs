@@ -0,0 +1,153 @@
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// bucket跨域配置，最多10条规则
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::cors::{CorsConfiguration, CorsRule};
+/// let config = CorsConfiguration::new()
+///     .with_rule(CorsRule::new()
+///         .with_allowed_origin("https://www.example.com")
+///         .with_allowed_method("POST")
+///         .with_allowed_method("PUT")
+///         .with_allowed_header("*")
+///         .with_expose_header("ETag")
+///         .with_max_age_seconds(600));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "CORSConfiguration", rename_all = "PascalCase")]
+pub struct CorsConfiguration {
+    #[serde(rename = "CORSRule", default)]
+    pub rules: Vec<CorsRule>,
+    /// 是否返回`Vary: Origin`头
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_vary: Option<bool>,
+}
+
+impl CorsConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_rule(mut self, rule: CorsRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+    pub fn with_response_vary(mut self, response_vary: bool) -> Self {
+        self.response_vary = Some(response_vary);
+        self
+    }
+}
+
+/// 跨域规则，origin和header支持一个`*`通配符
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CorsRule {
+    #[serde(rename = "AllowedOrigin", default)]
+    pub allowed_origins: Vec<String>,
+    #[serde(rename = "AllowedMethod", default)]
+    pub allowed_methods: Vec<String>,
+    #[serde(rename = "AllowedHeader", default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_headers: Vec<String>,
+    #[serde(rename = "ExposeHeader", default, skip_serializing_if = "Vec::is_empty")]
+    pub expose_headers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u32>,
+}
+
+impl CorsRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_allowed_origin<S: AsRef<str>>(mut self, origin: S) -> Self {
+        self.allowed_origins.push(origin.as_ref().to_string());
+        self
+    }
+    /// GET/PUT/DELETE/POST/HEAD
+    pub fn with_allowed_method<S: AsRef<str>>(mut self, method: S) -> Self {
+        self.allowed_methods.push(method.as_ref().to_uppercase());
+        self
+    }
+    pub fn with_allowed_header<S: AsRef<str>>(mut self, header: S) -> Self {
+        self.allowed_headers.push(header.as_ref().to_string());
+        self
+    }
+    pub fn with_expose_header<S: AsRef<str>>(mut self, header: S) -> Self {
+        self.expose_headers.push(header.as_ref().to_string());
+        self
+    }
+    pub fn with_max_age_seconds(mut self, max_age_seconds: u32) -> Self {
+        self.max_age_seconds = Some(max_age_seconds);
+        self
+    }
+}
+
+/// 预检请求(OPTIONS)的响应
+#[derive(Debug, Clone, Default)]
+pub struct PreflightResponse {
+    pub allow_origin: Option<String>,
+    pub allow_methods: Vec<String>,
+    pub allow_headers: Vec<String>,
+    pub expose_headers: Vec<String>,
+    pub max_age: Option<u32>,
+}
+
+impl PreflightResponse {
+    pub(crate) fn new(headers: &HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(|v| v.to_string());
+        let list = |name: &str| {
+            get(name)
+                .map(|v| v.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+                .unwrap_or_default()
+        };
+        PreflightResponse {
+            allow_origin: get("access-control-allow-origin"),
+            allow_methods: list("access-control-allow-methods"),
+            allow_headers: list("access-control-allow-headers"),
+            expose_headers: list("access-control-expose-headers"),
+            max_age: get("access-control-max-age").and_then(|v| v.parse().ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderMap;
+    use crate::cors::{CorsConfiguration, CorsRule, PreflightResponse};
+
+    #[test]
+    fn test_cors_configuration_xml() {
+        let config = CorsConfiguration::new()
+            .with_rule(CorsRule::new()
+                .with_allowed_origin("*")
+                .with_allowed_method("put")
+                .with_allowed_method("GET")
+                .with_allowed_header("Authorization")
+                .with_max_age_seconds(100))
+            .with_response_vary(false);
+        let xml = quick_xml::se::to_string(&config).unwrap();
+        assert_eq!(
+            xml,
+            "<CORSConfiguration><CORSRule><AllowedOrigin>*</AllowedOrigin><AllowedMethod>PUT</AllowedMethod>\
+<AllowedMethod>GET</AllowedMethod><AllowedHeader>Authorization</AllowedHeader><MaxAgeSeconds>100</MaxAgeSeconds>\
+</CORSRule><ResponseVary>false</ResponseVary></CORSConfiguration>"
+        );
+        let parsed: CorsConfiguration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.rules[0].allowed_methods, vec!["PUT", "GET"]);
+        assert!(parsed.rules[0].expose_headers.is_empty());
+    }
+
+    #[test]
+    fn test_preflight_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert("access-control-allow-origin", "https://www.example.com".parse().unwrap());
+        headers.insert("access-control-allow-methods", "PUT, POST".parse().unwrap());
+        headers.insert("access-control-max-age", "600".parse().unwrap());
+        let response = PreflightResponse::new(&headers);
+        assert_eq!(response.allow_origin.as_deref(), Some("https://www.example.com"));
+        assert_eq!(response.allow_methods, vec!["PUT", "POST"]);
+        assert!(response.allow_headers.is_empty());
+        assert_eq!(response.max_age, Some(600));
+    }
+}
@@ -0,0 +1,146 @@
+//! OSS使用的CRC64-ECMA校验(与CRC-64/XZ相同)，结果与响应头`x-oss-hash-crc64ecma`比较
+
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const TABLE: [u64; 256] = make_table();
+
+const fn make_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ POLY } else { crc >> 1 };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// 增量计算CRC64，适合分块读取的数据
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::crc64::{crc64, Crc64};
+/// let mut crc = Crc64::new();
+/// crc.update(b"1234");
+/// crc.update(b"56789");
+/// assert_eq!(crc.finalize(), crc64(b"123456789"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crc64 {
+    crc: u64,
+}
+
+impl Crc64 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = !self.crc;
+        for b in data {
+            crc = TABLE[((crc ^ *b as u64) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.crc = !crc;
+    }
+
+    pub fn finalize(&self) -> u64 {
+        self.crc
+    }
+}
+
+pub fn crc64(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.finalize()
+}
+
+/// 合并两段数据的CRC64，`crc2`对应的数据长度为`len2`，用于追加上传和分片上传
+pub fn combine(crc1: u64, crc2: u64, len2: u64) -> u64 {
+    if len2 == 0 {
+        return crc1;
+    }
+    // 与zlib的crc32_combine相同，用GF(2)矩阵计算在crc1后追加len2个0字节的结果
+    let mut odd = [0u64; 64];
+    let mut even = [0u64; 64];
+    odd[0] = POLY;
+    let mut row = 1u64;
+    for item in odd.iter_mut().skip(1) {
+        *item = row;
+        row <<= 1;
+    }
+    gf2_matrix_square(&mut even, &odd);
+    gf2_matrix_square(&mut odd, &even);
+
+    let mut crc1 = crc1;
+    let mut len2 = len2;
+    loop {
+        gf2_matrix_square(&mut even, &odd);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&even, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+        gf2_matrix_square(&mut odd, &even);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&odd, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+    }
+    crc1 ^ crc2
+}
+
+fn gf2_matrix_times(mat: &[u64; 64], vec: u64) -> u64 {
+    let mut sum = 0;
+    let mut vec = vec;
+    let mut i = 0;
+    while vec != 0 {
+        if vec & 1 == 1 {
+            sum ^= mat[i];
+        }
+        vec >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
+    for n in 0..64 {
+        square[n] = gf2_matrix_times(mat, mat[n]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crc64::{combine, crc64, Crc64};
+
+    #[test]
+    fn test_crc64() {
+        assert_eq!(crc64(b""), 0);
+        assert_eq!(crc64(b"123456789"), 0x995D_C9BB_DF19_39FA);
+        let mut crc = Crc64::new();
+        for chunk in b"hello world".chunks(3) {
+            crc.update(chunk);
+        }
+        assert_eq!(crc.finalize(), crc64(b"hello world"));
+    }
+
+    #[test]
+    fn test_crc64_combine() {
+        let data = (0..10_000u32).map(|i| (i * 31 % 256) as u8).collect::<Vec<_>>();
+        for split in [0, 1, 7, 4096, 9999, 10_000] {
+            let (a, b) = data.split_at(split);
+            assert_eq!(combine(crc64(a), crc64(b), b.len() as u64), crc64(&data));
+        }
+    }
+}
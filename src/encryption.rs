@@ -0,0 +1,392 @@
+//! 客户端加密，数据在本地加密后再上传，OSS上只保存密文
+//!
+//! 每个文件随机生成AES-256-CTR数据密钥，数据密钥和IV由主密钥(`MasterKey`)加密后保存在
+//! `x-oss-meta-client-side-encryption-*`元数据中，格式与官方Java/Go SDK一致，可以互相读取
+use std::collections::HashMap;
+use std::sync::Arc;
+use aes::Aes256;
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use rand::RngCore;
+use reqwest::header::{HeaderMap, CONTENT_RANGE};
+use reqwest::StatusCode;
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::{Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey};
+use crate::error::OssError;
+use crate::oss::OSS;
+use crate::util;
+
+pub const CLIENT_SIDE_ENCRYPTION_KEY: &str = "x-oss-meta-client-side-encryption-key";
+pub const CLIENT_SIDE_ENCRYPTION_START: &str = "x-oss-meta-client-side-encryption-start";
+pub const CLIENT_SIDE_ENCRYPTION_CEK_ALG: &str = "x-oss-meta-client-side-encryption-cek-alg";
+pub const CLIENT_SIDE_ENCRYPTION_WRAP_ALG: &str = "x-oss-meta-client-side-encryption-wrap-alg";
+pub const CLIENT_SIDE_ENCRYPTION_MATDESC: &str = "x-oss-meta-client-side-encryption-matdesc";
+pub const CLIENT_SIDE_ENCRYPTION_UNENCRYPTED_CONTENT_LENGTH: &str =
+    "x-oss-meta-client-side-encryption-unencrypted-content-length";
+
+/// 数据加密算法
+pub const AES_CTR_ALGORITHM: &str = "AES/CTR/NoPadding";
+/// RSA主密钥的密钥加密算法
+pub const RSA_WRAP_ALGORITHM: &str = "RSA/NONE/PKCS1Padding";
+/// KMS主密钥的密钥加密算法
+pub const KMS_WRAP_ALGORITHM: &str = "KMS/ALICLOUD";
+
+type Aes256Ctr = ctr::Ctr128BE<Aes256>;
+
+/// 主密钥，用于加密和解密每个文件的数据密钥
+pub trait MasterKey: Send + Sync {
+    /// 保存在`x-oss-meta-client-side-encryption-wrap-alg`中的算法名
+    fn wrap_algorithm(&self) -> &str;
+    /// 主密钥的描述信息，保存在`x-oss-meta-client-side-encryption-matdesc`中
+    fn mat_desc(&self) -> &HashMap<String, String>;
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError>;
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError>;
+}
+
+/// RSA主密钥，使用PKCS1v15填充
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::encryption::RsaMasterKey;
+/// let public_key = std::fs::read_to_string("rsa_public.pem").unwrap();
+/// let private_key = std::fs::read_to_string("rsa_private.pem").unwrap();
+/// let master_key = RsaMasterKey::from_pem(&public_key, &private_key).unwrap()
+///     .with_mat_desc("tenant", "a");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RsaMasterKey {
+    public_key: RsaPublicKey,
+    private_key: RsaPrivateKey,
+    mat_desc: HashMap<String, String>,
+}
+
+impl RsaMasterKey {
+    pub fn new(public_key: RsaPublicKey, private_key: RsaPrivateKey) -> Self {
+        Self {
+            public_key,
+            private_key,
+            mat_desc: HashMap::new(),
+        }
+    }
+
+    /// 公钥支持PKCS#1和X.509(PKIX)格式，私钥支持PKCS#1和PKCS#8格式
+    pub fn from_pem<S: AsRef<str>>(public_key: S, private_key: S) -> Result<Self, OssError> {
+        let public_key = public_key.as_ref();
+        let public_key = RsaPublicKey::from_public_key_pem(public_key)
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(public_key))
+            .map_err(|e| OssError::Err(format!("invalid rsa public key: {}", e)))?;
+        let private_key = private_key.as_ref();
+        let private_key = RsaPrivateKey::from_pkcs8_pem(private_key)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(private_key))
+            .map_err(|e| OssError::Err(format!("invalid rsa private key: {}", e)))?;
+        Ok(Self::new(public_key, private_key))
+    }
+
+    pub fn with_mat_desc<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.mat_desc.insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
+
+impl MasterKey for RsaMasterKey {
+    fn wrap_algorithm(&self) -> &str {
+        RSA_WRAP_ALGORITHM
+    }
+
+    fn mat_desc(&self) -> &HashMap<String, String> {
+        &self.mat_desc
+    }
+
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+        self.public_key
+            .encrypt(&mut rand::thread_rng(), Pkcs1v15Encrypt, data)
+            .map_err(|e| OssError::Err(format!("rsa encrypt error: {}", e)))
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+        self.private_key
+            .decrypt(Pkcs1v15Encrypt, data)
+            .map_err(|e| OssError::Err(format!("rsa decrypt error: {}", e)))
+    }
+}
+
+/// 调用KMS加解密的接口，由使用者基于KMS SDK实现
+///
+/// 与官方SDK互通时，`encrypt`的明文需要base64编码后发给KMS，返回KMS响应中的`CiphertextBlob`；
+/// `decrypt`把`CiphertextBlob`发给KMS，返回base64解码后的`Plaintext`
+pub trait Kms: Send + Sync {
+    fn encrypt(&self, key_id: &str, plaintext: &[u8]) -> Result<Vec<u8>, OssError>;
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, OssError>;
+}
+
+/// KMS主密钥
+#[derive(Debug, Clone)]
+pub struct KmsMasterKey<K: Kms> {
+    kms: K,
+    key_id: String,
+    mat_desc: HashMap<String, String>,
+}
+
+impl<K: Kms> KmsMasterKey<K> {
+    pub fn new<S: AsRef<str>>(kms: K, key_id: S) -> Self {
+        Self {
+            kms,
+            key_id: key_id.as_ref().to_string(),
+            mat_desc: HashMap::new(),
+        }
+    }
+
+    pub fn with_mat_desc<MK: AsRef<str>, MV: AsRef<str>>(mut self, key: MK, value: MV) -> Self {
+        self.mat_desc.insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
+
+impl<K: Kms> MasterKey for KmsMasterKey<K> {
+    fn wrap_algorithm(&self) -> &str {
+        KMS_WRAP_ALGORITHM
+    }
+
+    fn mat_desc(&self) -> &HashMap<String, String> {
+        &self.mat_desc
+    }
+
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+        self.kms.encrypt(&self.key_id, data)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+        self.kms.decrypt(data)
+    }
+}
+
+/// 单个文件的数据密钥和IV
+#[derive(Clone)]
+pub(crate) struct ContentCipher {
+    key: [u8; 32],
+    iv: [u8; 16],
+}
+
+impl ContentCipher {
+    /// 随机生成数据密钥和IV，IV的第8~11字节置0，避免计数器溢出
+    pub(crate) fn generate() -> Self {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut iv);
+        iv[8..12].fill(0);
+        Self { key, iv }
+    }
+
+    /// 加密或解密从明文`offset`字节开始的数据
+    pub(crate) fn apply(&self, data: &mut [u8], offset: u64) {
+        let mut cipher = Aes256Ctr::new(&self.key.into(), &self.iv.into());
+        cipher.seek(offset);
+        cipher.apply_keystream(data);
+    }
+
+    /// 使用主密钥加密后生成元数据请求头
+    pub(crate) fn headers(
+        &self,
+        master_key: &dyn MasterKey,
+        content_length: usize,
+    ) -> Result<Vec<(String, String)>, OssError> {
+        let mut headers = vec![
+            (CLIENT_SIDE_ENCRYPTION_KEY.to_string(), util::base64_encode(master_key.encrypt(&self.key)?)),
+            (CLIENT_SIDE_ENCRYPTION_START.to_string(), util::base64_encode(master_key.encrypt(&self.iv)?)),
+            (CLIENT_SIDE_ENCRYPTION_CEK_ALG.to_string(), AES_CTR_ALGORITHM.to_string()),
+            (CLIENT_SIDE_ENCRYPTION_WRAP_ALG.to_string(), master_key.wrap_algorithm().to_string()),
+            (CLIENT_SIDE_ENCRYPTION_UNENCRYPTED_CONTENT_LENGTH.to_string(), content_length.to_string()),
+        ];
+        if !master_key.mat_desc().is_empty() {
+            headers.push((CLIENT_SIDE_ENCRYPTION_MATDESC.to_string(), serde_json::to_string(master_key.mat_desc())?));
+        }
+        Ok(headers)
+    }
+
+    /// 从响应头中解出数据密钥和IV，文件未加密时返回None
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+        master_key: &dyn MasterKey,
+    ) -> Result<Option<Self>, OssError> {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let (key, iv) = match (get(CLIENT_SIDE_ENCRYPTION_KEY), get(CLIENT_SIDE_ENCRYPTION_START)) {
+            (Some(key), Some(iv)) => (key, iv),
+            _ => return Ok(None),
+        };
+        let cek_alg = get(CLIENT_SIDE_ENCRYPTION_CEK_ALG).unwrap_or(AES_CTR_ALGORITHM);
+        if cek_alg != AES_CTR_ALGORITHM {
+            return Err(OssError::Err(format!("unsupported content encryption algorithm: {}", cek_alg)));
+        }
+        let wrap_alg = get(CLIENT_SIDE_ENCRYPTION_WRAP_ALG).unwrap_or_default();
+        if !wrap_alg.eq_ignore_ascii_case(master_key.wrap_algorithm()) {
+            return Err(OssError::Err(format!(
+                "object is encrypted with {}, but master key is {}",
+                wrap_alg,
+                master_key.wrap_algorithm()
+            )));
+        }
+        let key = master_key.decrypt(&util::base64_decode(key)?)?;
+        let iv = master_key.decrypt(&util::base64_decode(iv)?)?;
+        let key: [u8; 32] = key
+            .try_into()
+            .map_err(|_| OssError::Err("invalid client side encryption key length".to_string()))?;
+        let iv: [u8; 16] = iv
+            .try_into()
+            .map_err(|_| OssError::Err("invalid client side encryption iv length".to_string()))?;
+        Ok(Some(Self { key, iv }))
+    }
+}
+
+/// 客户端加密的bucket句柄
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::encryption::{CryptoOSS, RsaMasterKey};
+/// use aliyun_oss_rust_sdk::oss::OSS;
+/// use aliyun_oss_rust_sdk::request::RequestBuilder;
+/// let public_key = std::fs::read_to_string("rsa_public.pem").unwrap();
+/// let private_key = std::fs::read_to_string("rsa_private.pem").unwrap();
+/// let master_key = RsaMasterKey::from_pem(&public_key, &private_key).unwrap();
+/// let crypto = CryptoOSS::new(OSS::from_env(), master_key);
+/// crypto.put_object_from_buffer("/tenant/a.txt", "secret".as_bytes(), RequestBuilder::new()).await.unwrap();
+/// let bytes = crypto.get_object("/tenant/a.txt", RequestBuilder::new()).await.unwrap();
+/// ```
+#[derive(Clone)]
+pub struct CryptoOSS {
+    oss: OSS,
+    master_key: Arc<dyn MasterKey>,
+}
+
+impl CryptoOSS {
+    pub fn new<M: MasterKey + 'static>(oss: OSS, master_key: M) -> Self {
+        Self {
+            oss,
+            master_key: Arc::new(master_key),
+        }
+    }
+
+    /// 未加密的操作(删除、元数据等)直接使用内部的`OSS`
+    pub fn oss(&self) -> &OSS {
+        &self.oss
+    }
+
+    pub(crate) fn master_key(&self) -> &dyn MasterKey {
+        self.master_key.as_ref()
+    }
+}
+
+/// 根据响应计算解密偏移，206时从`Content-Range: bytes start-end/total`中解析起始位置，
+/// 其它状态(例如OSS忽略了无效的Range)返回完整文件，偏移为None
+pub(crate) fn content_offset(status: StatusCode, headers: &HeaderMap) -> Result<Option<u64>, OssError> {
+    if status != StatusCode::PARTIAL_CONTENT {
+        return Ok(None);
+    }
+    let content_range = headers
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    content_range
+        .trim()
+        .strip_prefix("bytes ")
+        .and_then(|r| r.split_once('-'))
+        .and_then(|(start, _)| start.trim().parse().ok())
+        .map(Some)
+        .ok_or_else(|| OssError::Err(format!("invalid Content-Range for client side encryption: {}", content_range)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use reqwest::header::{HeaderMap, HeaderName, CONTENT_RANGE};
+    use reqwest::StatusCode;
+    use rsa::RsaPrivateKey;
+    use crate::encryption::{content_offset, ContentCipher, MasterKey, RsaMasterKey, AES_CTR_ALGORITHM, CLIENT_SIDE_ENCRYPTION_CEK_ALG, CLIENT_SIDE_ENCRYPTION_MATDESC, CLIENT_SIDE_ENCRYPTION_WRAP_ALG, RSA_WRAP_ALGORITHM};
+    use crate::error::OssError;
+
+    struct XorMasterKey(HashMap<String, String>);
+
+    impl MasterKey for XorMasterKey {
+        fn wrap_algorithm(&self) -> &str {
+            "XOR"
+        }
+        fn mat_desc(&self) -> &HashMap<String, String> {
+            &self.0
+        }
+        fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+            Ok(data.iter().map(|b| b ^ 0x5a).collect())
+        }
+        fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, OssError> {
+            self.encrypt(data)
+        }
+    }
+
+    fn to_header_map(headers: Vec<(String, String)>) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (k, v) in headers {
+            map.insert(HeaderName::from_bytes(k.as_bytes()).unwrap(), v.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_content_cipher_ranged_decrypt() {
+        let cipher = ContentCipher::generate();
+        assert_eq!(cipher.iv[8..12], [0, 0, 0, 0]);
+        let plain = (0..1000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let mut encrypted = plain.clone();
+        cipher.apply(&mut encrypted, 0);
+        assert_ne!(encrypted, plain);
+        for (start, end) in [(0, 10), (15, 17), (16, 48), (333, 1000)] {
+            let mut part = encrypted[start..end].to_vec();
+            cipher.apply(&mut part, start as u64);
+            assert_eq!(part, plain[start..end]);
+        }
+    }
+
+    #[test]
+    fn test_content_cipher_headers() {
+        let mut mat_desc = HashMap::new();
+        mat_desc.insert("tenant".to_string(), "a".to_string());
+        let master_key = XorMasterKey(mat_desc);
+        let cipher = ContentCipher::generate();
+        let headers = to_header_map(cipher.headers(&master_key, 6).unwrap());
+        assert_eq!(headers.get(CLIENT_SIDE_ENCRYPTION_CEK_ALG).unwrap(), AES_CTR_ALGORITHM);
+        assert_eq!(headers.get(CLIENT_SIDE_ENCRYPTION_WRAP_ALG).unwrap(), "XOR");
+        assert_eq!(headers.get(CLIENT_SIDE_ENCRYPTION_MATDESC).unwrap(), r#"{"tenant":"a"}"#);
+        let parsed = ContentCipher::from_headers(&headers, &master_key).unwrap().unwrap();
+        assert_eq!(parsed.key, cipher.key);
+        assert_eq!(parsed.iv, cipher.iv);
+        assert!(ContentCipher::from_headers(&HeaderMap::new(), &master_key).unwrap().is_none());
+
+        let rsa = RsaMasterKey::new(
+            RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap().to_public_key(),
+            RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap(),
+        );
+        assert!(ContentCipher::from_headers(&headers, &rsa).is_err());
+    }
+
+    #[test]
+    fn test_rsa_master_key() {
+        let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let master_key = RsaMasterKey::new(private_key.to_public_key(), private_key);
+        assert_eq!(master_key.wrap_algorithm(), RSA_WRAP_ALGORITHM);
+        let encrypted = master_key.encrypt(b"0123456789abcdef").unwrap();
+        assert_eq!(encrypted.len(), 128);
+        assert_eq!(master_key.decrypt(&encrypted).unwrap(), b"0123456789abcdef");
+    }
+
+    #[test]
+    fn test_content_offset() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(content_offset(StatusCode::OK, &headers).unwrap(), None);
+        assert!(content_offset(StatusCode::PARTIAL_CONTENT, &headers).is_err());
+        headers.insert(CONTENT_RANGE, "bytes 100-199/1000".parse().unwrap());
+        assert_eq!(content_offset(StatusCode::PARTIAL_CONTENT, &headers).unwrap(), Some(100));
+        //OSS忽略Range返回200时按完整文件从0开始解密
+        assert_eq!(content_offset(StatusCode::OK, &headers).unwrap(), None);
+    }
+}
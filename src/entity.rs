@@ -1,4 +1,6 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use crate::callback::Callback;
 use crate::request::Seconds;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +10,12 @@ pub struct PolicyResp {
     pub policy: String,
     pub signature: String,
     pub success_action_status: u8,
+    /// 上传回调，表单中的`callback`字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback: Option<String>,
+    /// 上传回调的自定义变量，作为`x:`开头的表单字段
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub callback_vars: BTreeMap<String, String>,
 }
 
 unsafe impl Send for PolicyResp {}
@@ -21,6 +29,7 @@ pub struct PolicyBuilder {
     pub upload_dir: String,
     pub content_type: String,
     pub max_upload_size: i64,
+    pub callback: Option<Callback>,
 }
 
 unsafe impl Send for PolicyBuilder {}
@@ -40,6 +49,7 @@ impl PolicyBuilder {
             upload_dir: "".to_string(),
             content_type: "text/plain".to_string(),//文本.txt
             max_upload_size: 100 * 1024 * 1024,//100m
+            callback: None,
         }
     }
     pub fn with_expire(mut self, expire: Seconds) -> Self {
@@ -58,4 +68,27 @@ impl PolicyBuilder {
         self.max_upload_size = max_upload_size;
         self
     }
+    /// 上传完成后回调应用服务器
+    pub fn with_callback(mut self, callback: Callback) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+}
+
+/// 标签
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+impl Tag {
+    pub fn new<K: AsRef<str>, V: AsRef<str>>(key: K, value: V) -> Self {
+        Self {
+            key: key.as_ref().to_string(),
+            value: value.as_ref().to_string(),
+        }
+    }
 }
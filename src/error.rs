@@ -8,6 +8,22 @@ pub enum OssError {
     JsonError(#[from] serde_json::Error),
     #[error("base64 decode error: {0}")]
     DecodeError(#[from] base64::DecodeError),
+    #[error("xml error: {0}")]
+    XmlError(#[from] quick_xml::DeError),
+    #[error("build request error: {0}")]
+    InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("{algorithm} mismatch, expected: {expected}, actual: {actual}")]
+    IntegrityMismatch {
+        algorithm: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("callback failed: {0}")]
+    CallbackFailed(String),
+    #[error("invalid presigned url: {0}")]
+    InvalidPresignedUrl(String),
+    #[error("invalid callback signature: {0}")]
+    InvalidCallbackSignature(String),
     #[error("{0}")]
     Err(String),
 }
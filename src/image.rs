@@ -0,0 +1,438 @@
+use std::fmt::{Display, Formatter};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde::{Deserialize, Serialize};
+
+/// 图片处理参数`x-oss-process`的值，按添加顺序依次处理
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::image::{Gravity, ImageFormat, ImageProcess, Resize, ResizeMode, Watermark};
+/// use aliyun_oss_rust_sdk::oss::OSS;
+/// use aliyun_oss_rust_sdk::request::RequestBuilder;
+/// let oss = OSS::from_env();
+/// let process = ImageProcess::new()
+///     .with_auto_orient(true)
+///     .with_resize(Resize::new(ResizeMode::Lfit).with_width(200).with_height(200))
+///     .with_watermark(Watermark::text("ipadump.com").with_position(Gravity::SouthEast, 10, 10))
+///     .with_format(ImageFormat::Webp)
+///     .with_quality(80);
+/// assert_eq!(
+///     process.to_string(),
+///     "image/auto-orient,1/resize,m_lfit,w_200,h_200/watermark,text_aXBhZHVtcC5jb20=,g_se,x_10,y_10/format,webp/quality,Q_80"
+/// );
+/// let build = RequestBuilder::new().with_image_process(&process);
+/// let thumbnail = oss.get_object("/photo.jpg", build).await.unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageProcess {
+    actions: Vec<String>,
+}
+
+impl Display for ImageProcess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "image")?;
+        for action in &self.actions {
+            write!(f, "/{}", action)?;
+        }
+        Ok(())
+    }
+}
+
+impl ImageProcess {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_action(mut self, name: &str, params: Vec<String>) -> Self {
+        let mut action = vec![name.to_string()];
+        action.extend(params);
+        self.actions.push(action.join(","));
+        self
+    }
+
+    /// 缩放
+    pub fn with_resize(self, resize: Resize) -> Self {
+        self.with_action("resize", resize.params())
+    }
+
+    /// 按原图的百分比缩放，1~1000
+    pub fn with_resize_percent(self, percent: u32) -> Self {
+        self.with_action("resize", vec![format!("p_{}", percent)])
+    }
+
+    /// 裁剪，`gravity`为起点所在的区域，默认左上角
+    pub fn with_crop(self, x: u32, y: u32, width: u32, height: u32, gravity: Option<Gravity>) -> Self {
+        let mut params = vec![
+            format!("x_{}", x),
+            format!("y_{}", y),
+            format!("w_{}", width),
+            format!("h_{}", height),
+        ];
+        if let Some(gravity) = gravity {
+            params.push(format!("g_{}", gravity));
+        }
+        self.with_action("crop", params)
+    }
+
+    /// 顺时针旋转，0~360度
+    pub fn with_rotate(self, degree: u32) -> Self {
+        self.with_action("rotate", vec![degree.to_string()])
+    }
+
+    /// 绝对质量，1~100，只对jpg和webp有效
+    pub fn with_quality(self, quality: u8) -> Self {
+        self.with_action("quality", vec![format!("Q_{}", quality)])
+    }
+
+    /// 相对原图的质量，1~100
+    pub fn with_relative_quality(self, quality: u8) -> Self {
+        self.with_action("quality", vec![format!("q_{}", quality)])
+    }
+
+    /// 格式转换
+    pub fn with_format(self, format: ImageFormat) -> Self {
+        self.with_action("format", vec![format.to_string()])
+    }
+
+    pub fn with_watermark(self, watermark: Watermark) -> Self {
+        self.with_action("watermark", watermark.params())
+    }
+
+    /// 模糊，`radius`和`sigma`均为1~50
+    pub fn with_blur(self, radius: u8, sigma: u8) -> Self {
+        self.with_action("blur", vec![format!("r_{}", radius), format!("s_{}", sigma)])
+    }
+
+    /// 按EXIF信息自动旋转
+    pub fn with_auto_orient(self, auto_orient: bool) -> Self {
+        self.with_action("auto-orient", vec![(auto_orient as u8).to_string()])
+    }
+}
+
+/// 在控制台创建的图片样式，渲染为`style/{name}`
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::image::ImageStyle;
+/// use aliyun_oss_rust_sdk::request::RequestBuilder;
+/// let build = RequestBuilder::new().with_image_process(ImageStyle::new("thumbnail"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageStyle(String);
+
+impl ImageStyle {
+    pub fn new<S: AsRef<str>>(name: S) -> Self {
+        Self(name.as_ref().to_string())
+    }
+}
+
+impl Display for ImageStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "style/{}", self.0)
+    }
+}
+
+/// 缩放模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeMode {
+    /// 等比缩放，限制在指定宽高的矩形内
+    #[default]
+    Lfit,
+    /// 等比缩放，延伸出指定宽高的矩形外
+    Mfit,
+    /// 等比缩放后居中裁剪为指定宽高
+    Fill,
+    /// 等比缩放后居中填充为指定宽高
+    Pad,
+    /// 强制缩放为指定宽高
+    Fixed,
+}
+
+impl Display for ResizeMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResizeMode::Lfit => write!(f, "lfit"),
+            ResizeMode::Mfit => write!(f, "mfit"),
+            ResizeMode::Fill => write!(f, "fill"),
+            ResizeMode::Pad => write!(f, "pad"),
+            ResizeMode::Fixed => write!(f, "fixed"),
+        }
+    }
+}
+
+/// 缩放参数
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Resize {
+    mode: ResizeMode,
+    width: Option<u32>,
+    height: Option<u32>,
+    long: Option<u32>,
+    short: Option<u32>,
+    limit: Option<bool>,
+    color: Option<String>,
+}
+
+impl Resize {
+    pub fn new(mode: ResizeMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+    /// 长边
+    pub fn with_long(mut self, long: u32) -> Self {
+        self.long = Some(long);
+        self
+    }
+    /// 短边
+    pub fn with_short(mut self, short: u32) -> Self {
+        self.short = Some(short);
+        self
+    }
+    /// 目标尺寸大于原图时是否不放大，默认不放大
+    pub fn with_limit(mut self, limit: bool) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    /// `Pad`模式的填充颜色，RGB十六进制，例如`FFFFFF`
+    pub fn with_color<S: AsRef<str>>(mut self, color: S) -> Self {
+        self.color = Some(color.as_ref().trim_start_matches('#').to_uppercase());
+        self
+    }
+
+    fn params(&self) -> Vec<String> {
+        let mut params = vec![format!("m_{}", self.mode)];
+        params.extend(self.width.map(|v| format!("w_{}", v)));
+        params.extend(self.height.map(|v| format!("h_{}", v)));
+        params.extend(self.long.map(|v| format!("l_{}", v)));
+        params.extend(self.short.map(|v| format!("s_{}", v)));
+        params.extend(self.limit.map(|v| format!("limit_{}", v as u8)));
+        params.extend(self.color.as_ref().map(|v| format!("color_{}", v)));
+        params
+    }
+}
+
+/// 位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gravity {
+    NorthWest,
+    North,
+    NorthEast,
+    West,
+    Center,
+    East,
+    SouthWest,
+    South,
+    SouthEast,
+}
+
+impl Display for Gravity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Gravity::NorthWest => write!(f, "nw"),
+            Gravity::North => write!(f, "north"),
+            Gravity::NorthEast => write!(f, "ne"),
+            Gravity::West => write!(f, "west"),
+            Gravity::Center => write!(f, "center"),
+            Gravity::East => write!(f, "east"),
+            Gravity::SouthWest => write!(f, "sw"),
+            Gravity::South => write!(f, "south"),
+            Gravity::SouthEast => write!(f, "se"),
+        }
+    }
+}
+
+/// 目标格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpg,
+    Png,
+    Webp,
+    Bmp,
+    Gif,
+    Tiff,
+    Heic,
+    Avif,
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageFormat::Jpg => write!(f, "jpg"),
+            ImageFormat::Png => write!(f, "png"),
+            ImageFormat::Webp => write!(f, "webp"),
+            ImageFormat::Bmp => write!(f, "bmp"),
+            ImageFormat::Gif => write!(f, "gif"),
+            ImageFormat::Tiff => write!(f, "tiff"),
+            ImageFormat::Heic => write!(f, "heic"),
+            ImageFormat::Avif => write!(f, "avif"),
+        }
+    }
+}
+
+/// 水印，文字和图片key使用URL安全的Base64编码(保留`=`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Watermark {
+    content: Vec<String>,
+    transparency: Option<u8>,
+    position: Option<(Gravity, u32, u32)>,
+}
+
+impl Watermark {
+    /// 文字水印
+    pub fn text<S: AsRef<str>>(text: S) -> Self {
+        Self {
+            content: vec![format!("text_{}", base64_url(text.as_ref()))],
+            transparency: None,
+            position: None,
+        }
+    }
+
+    /// 图片水印，`key`为同一bucket内的图片
+    pub fn image<S: AsRef<str>>(key: S) -> Self {
+        Self {
+            content: vec![format!("image_{}", base64_url(key.as_ref().trim_start_matches('/')))],
+            transparency: None,
+            position: None,
+        }
+    }
+
+    /// 文字字体，例如`wqy-zenhei`
+    pub fn with_font<S: AsRef<str>>(mut self, font: S) -> Self {
+        self.content.push(format!("type_{}", base64_url(font.as_ref())));
+        self
+    }
+
+    /// 文字颜色，RGB十六进制，例如`000000`
+    pub fn with_color<S: AsRef<str>>(mut self, color: S) -> Self {
+        self.content.push(format!("color_{}", color.as_ref().trim_start_matches('#').to_uppercase()));
+        self
+    }
+
+    /// 文字大小，单位px
+    pub fn with_size(mut self, size: u32) -> Self {
+        self.content.push(format!("size_{}", size));
+        self
+    }
+
+    /// 不透明度，0~100
+    pub fn with_transparency(mut self, transparency: u8) -> Self {
+        self.transparency = Some(transparency);
+        self
+    }
+
+    /// 位置和边距
+    pub fn with_position(mut self, gravity: Gravity, x: u32, y: u32) -> Self {
+        self.position = Some((gravity, x, y));
+        self
+    }
+
+    fn params(&self) -> Vec<String> {
+        let mut params = self.content.clone();
+        params.extend(self.transparency.map(|v| format!("t_{}", v)));
+        if let Some((gravity, x, y)) = self.position {
+            params.push(format!("g_{}", gravity));
+            params.push(format!("x_{}", x));
+            params.push(format!("y_{}", y));
+        }
+        params
+    }
+}
+
+/// 处理结果另存为文件的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ProcessObjectResult {
+    pub bucket: String,
+    pub file_size: u64,
+    pub object: String,
+    pub status: String,
+}
+
+/// 另存为的处理参数：`{process}|sys/saveas,o_{key},b_{bucket}`
+pub(crate) fn save_as<P: Display>(process: P, target_bucket: Option<&str>, target_key: &str) -> String {
+    let mut save_as = format!(
+        "{}|sys/saveas,o_{}",
+        process,
+        base64_url(target_key.trim_start_matches('/'))
+    );
+    if let Some(bucket) = target_bucket {
+        save_as.push_str(&format!(",b_{}", base64_url(bucket)));
+    }
+    save_as
+}
+
+/// URL安全的Base64编码，保留末尾的`=`
+pub(crate) fn base64_url<S: AsRef<[u8]>>(content: S) -> String {
+    general_purpose::URL_SAFE.encode(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::{base64_url, save_as, Gravity, ImageFormat, ImageProcess, ImageStyle, ProcessObjectResult, Resize, ResizeMode, Watermark};
+
+    #[test]
+    fn test_image_process() {
+        let process = ImageProcess::new()
+            .with_resize(Resize::new(ResizeMode::Pad).with_width(100).with_height(50).with_color("#ff0000"))
+            .with_crop(10, 20, 30, 40, Some(Gravity::Center))
+            .with_rotate(90)
+            .with_blur(3, 2)
+            .with_relative_quality(90)
+            .with_format(ImageFormat::Png);
+        assert_eq!(
+            process.to_string(),
+            "image/resize,m_pad,w_100,h_50,color_FF0000/crop,x_10,y_20,w_30,h_40,g_center/rotate,90/blur,r_3,s_2/quality,q_90/format,png"
+        );
+        let process = ImageProcess::new()
+            .with_resize(Resize::new(ResizeMode::Fill).with_long(300).with_limit(false))
+            .with_resize_percent(50);
+        assert_eq!(process.to_string(), "image/resize,m_fill,l_300,limit_0/resize,p_50");
+        assert_eq!(ImageStyle::new("thumbnail").to_string(), "style/thumbnail");
+    }
+
+    #[test]
+    fn test_watermark() {
+        assert_eq!(base64_url("水印?>"), "5rC05Y2wPz4=");
+        let process = ImageProcess::new().with_watermark(
+            Watermark::text("Hello 阿里云")
+                .with_font("wqy-zenhei")
+                .with_color("FFFFFF")
+                .with_size(40)
+                .with_transparency(50)
+                .with_position(Gravity::SouthEast, 10, 20),
+        );
+        assert_eq!(
+            process.to_string(),
+            "image/watermark,text_SGVsbG8g6Zi_6YeM5LqR,type_d3F5LXplbmhlaQ==,color_FFFFFF,size_40,t_50,g_se,x_10,y_20"
+        );
+        let process = ImageProcess::new().with_watermark(Watermark::image("/logo/panda.png"));
+        assert_eq!(process.to_string(), "image/watermark,image_bG9nby9wYW5kYS5wbmc=");
+    }
+
+    #[test]
+    fn test_save_as() {
+        let process = ImageProcess::new().with_resize_percent(50);
+        assert_eq!(
+            save_as(&process, Some("thumbnails"), "/thumb/a.jpg"),
+            "image/resize,p_50|sys/saveas,o_dGh1bWIvYS5qcGc=,b_dGh1bWJuYWlscw=="
+        );
+        assert_eq!(save_as(ImageStyle::new("small"), None, "a?.jpg"), "style/small|sys/saveas,o_YT8uanBn");
+        let json = r#"{"bucket":"thumbnails","fileSize":2663,"object":"thumb/a.jpg","status":"OK"}"#;
+        let result: ProcessObjectResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.file_size, 2663);
+        assert_eq!(result.object, "thumb/a.jpg");
+        assert_eq!(result.status, "OK");
+    }
+}
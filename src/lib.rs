@@ -1,9 +1,28 @@
 #![doc = include_str!("../README.md")]
+pub mod acl;
 pub mod auth;
+pub mod bucket;
+pub mod callback;
+pub mod client;
+pub mod cors;
+pub mod crc64;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod image;
 pub mod oss;
+pub mod policy;
 pub mod request;
 pub mod url;
+pub mod lifecycle;
 pub mod metadata;
+pub mod mime;
+pub mod multipart;
+pub mod referer;
+pub mod region;
+pub mod restore;
+pub mod sse;
+pub mod tagging;
+pub mod versioning;
 mod util;
 
 #[cfg(feature = "blocking")]
@@ -0,0 +1,272 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::bucket::StorageClass;
+use crate::entity::Tag;
+
+/// 规则状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleStatus {
+    Enabled,
+    Disabled,
+}
+
+/// 生命周期配置
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::bucket::StorageClass;
+/// use aliyun_oss_rust_sdk::lifecycle::{LifecycleConfiguration, LifecycleRule};
+/// let config = LifecycleConfiguration::new()
+///     .with_rule(LifecycleRule::new("logs", "log/")
+///         .with_transition_days(30, StorageClass::IA)
+///         .with_transition_days(180, StorageClass::Archive)
+///         .with_expiration_days(365))
+///     .with_rule(LifecycleRule::new("tmp", "")
+///         .with_tag("retention", "short")
+///         .with_expiration_days(7)
+///         .with_abort_multipart_upload_days(1));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "LifecycleConfiguration")]
+pub struct LifecycleConfiguration {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl LifecycleConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_rule(mut self, rule: LifecycleRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// 生命周期规则，按前缀和标签匹配文件，前缀为空表示整个bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleRule {
+    #[serde(rename = "ID", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub prefix: String,
+    pub status: RuleStatus,
+    #[serde(rename = "Tag", default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<LifecycleExpiration>,
+    #[serde(rename = "Transition", default, skip_serializing_if = "Vec::is_empty")]
+    pub transitions: Vec<LifecycleTransition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abort_multipart_upload: Option<AbortMultipartUpload>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+    #[serde(rename = "NoncurrentVersionTransition", default, skip_serializing_if = "Vec::is_empty")]
+    pub noncurrent_version_transitions: Vec<NoncurrentVersionTransition>,
+}
+
+impl LifecycleRule {
+    pub fn new<I: AsRef<str>, P: AsRef<str>>(id: I, prefix: P) -> Self {
+        Self {
+            id: Some(id.as_ref().to_string()),
+            prefix: prefix.as_ref().to_string(),
+            status: RuleStatus::Enabled,
+            tags: vec![],
+            expiration: None,
+            transitions: vec![],
+            abort_multipart_upload: None,
+            noncurrent_version_expiration: None,
+            noncurrent_version_transitions: vec![],
+        }
+    }
+    pub fn with_status(mut self, status: RuleStatus) -> Self {
+        self.status = status;
+        self
+    }
+    /// 只匹配带有该标签的文件，可设置多个
+    pub fn with_tag<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.tags.push(Tag::new(key, value));
+        self
+    }
+    /// 最后修改时间超过days天后删除
+    pub fn with_expiration_days(mut self, days: u32) -> Self {
+        self.expiration = Some(LifecycleExpiration {
+            days: Some(days),
+            ..Default::default()
+        });
+        self
+    }
+    /// 删除最后修改时间早于date的文件
+    pub fn with_expiration_date(mut self, date: NaiveDate) -> Self {
+        self.expiration = Some(LifecycleExpiration {
+            created_before_date: Some(format_date(date)),
+            ..Default::default()
+        });
+        self
+    }
+    /// 开启版本控制后，自动删除过期的删除标记
+    pub fn with_expired_object_delete_marker(mut self) -> Self {
+        self.expiration = Some(LifecycleExpiration {
+            expired_object_delete_marker: Some(true),
+            ..Default::default()
+        });
+        self
+    }
+    /// 最后修改时间超过days天后转换存储类型
+    pub fn with_transition_days(mut self, days: u32, storage_class: StorageClass) -> Self {
+        self.transitions.push(LifecycleTransition {
+            days: Some(days),
+            created_before_date: None,
+            storage_class,
+        });
+        self
+    }
+    /// 最后修改时间早于date的文件转换存储类型
+    pub fn with_transition_date(mut self, date: NaiveDate, storage_class: StorageClass) -> Self {
+        self.transitions.push(LifecycleTransition {
+            days: None,
+            created_before_date: Some(format_date(date)),
+            storage_class,
+        });
+        self
+    }
+    /// 删除超过days天未完成的分片上传
+    pub fn with_abort_multipart_upload_days(mut self, days: u32) -> Self {
+        self.abort_multipart_upload = Some(AbortMultipartUpload {
+            days: Some(days),
+            created_before_date: None,
+        });
+        self
+    }
+    /// 删除成为历史版本超过days天的文件
+    pub fn with_noncurrent_version_expiration_days(mut self, days: u32) -> Self {
+        self.noncurrent_version_expiration = Some(NoncurrentVersionExpiration { noncurrent_days: days });
+        self
+    }
+    /// 成为历史版本超过days天的文件转换存储类型
+    pub fn with_noncurrent_version_transition_days(mut self, days: u32, storage_class: StorageClass) -> Self {
+        self.noncurrent_version_transitions.push(NoncurrentVersionTransition {
+            noncurrent_days: days,
+            storage_class,
+        });
+        self
+    }
+}
+
+/// 过期删除，`days`、`created_before_date`、`expired_object_delete_marker`三选一
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleExpiration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// 格式为`2023-10-11T00:00:00.000Z`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+/// 存储类型转换，`days`和`created_before_date`二选一
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleTransition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before_date: Option<String>,
+    pub storage_class: StorageClass,
+}
+
+/// 未完成分片上传的清理，`days`和`created_before_date`二选一
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AbortMultipartUpload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NoncurrentVersionExpiration {
+    pub noncurrent_days: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NoncurrentVersionTransition {
+    pub noncurrent_days: u32,
+    pub storage_class: StorageClass,
+}
+
+fn format_date(date: NaiveDate) -> String {
+    format!("{}T00:00:00.000Z", date.format("%Y-%m-%d"))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::bucket::StorageClass;
+    use crate::lifecycle::{LifecycleConfiguration, LifecycleRule, RuleStatus};
+
+    #[test]
+    fn test_lifecycle_configuration_to_xml() {
+        let config = LifecycleConfiguration::new()
+            .with_rule(LifecycleRule::new("logs", "log/")
+                .with_tag("type", "access")
+                .with_transition_days(30, StorageClass::IA)
+                .with_transition_days(180, StorageClass::ColdArchive)
+                .with_expiration_date(NaiveDate::from_ymd_opt(2030, 1, 1).unwrap())
+                .with_abort_multipart_upload_days(3)
+                .with_noncurrent_version_expiration_days(5));
+        assert_eq!(
+            quick_xml::se::to_string(&config).unwrap(),
+            "<LifecycleConfiguration><Rule><ID>logs</ID><Prefix>log/</Prefix><Status>Enabled</Status>\
+<Tag><Key>type</Key><Value>access</Value></Tag>\
+<Expiration><CreatedBeforeDate>2030-01-01T00:00:00.000Z</CreatedBeforeDate></Expiration>\
+<Transition><Days>30</Days><StorageClass>IA</StorageClass></Transition>\
+<Transition><Days>180</Days><StorageClass>ColdArchive</StorageClass></Transition>\
+<AbortMultipartUpload><Days>3</Days></AbortMultipartUpload>\
+<NoncurrentVersionExpiration><NoncurrentDays>5</NoncurrentDays></NoncurrentVersionExpiration>\
+</Rule></LifecycleConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_lifecycle_configuration_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<LifecycleConfiguration>
+  <Rule>
+    <ID>delete after one day</ID>
+    <Prefix>logs1/</Prefix>
+    <Status>Enabled</Status>
+    <Expiration>
+      <Days>1</Days>
+    </Expiration>
+  </Rule>
+  <Rule>
+    <ID>mtime transition</ID>
+    <Prefix>logs2/</Prefix>
+    <Status>Disabled</Status>
+    <Transition>
+      <Days>30</Days>
+      <StorageClass>IA</StorageClass>
+    </Transition>
+    <NoncurrentVersionTransition>
+      <NoncurrentDays>10</NoncurrentDays>
+      <StorageClass>Archive</StorageClass>
+    </NoncurrentVersionTransition>
+  </Rule>
+</LifecycleConfiguration>"#;
+        let config: LifecycleConfiguration = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(config.rules.len(), 2);
+        assert_eq!(config.rules[0].expiration.as_ref().unwrap().days, Some(1));
+        assert_eq!(config.rules[1].status, RuleStatus::Disabled);
+        assert_eq!(config.rules[1].transitions[0].storage_class, StorageClass::IA);
+        assert_eq!(config.rules[1].noncurrent_version_transitions[0].noncurrent_days, 10);
+        assert!(config.rules[1].tags.is_empty());
+    }
+}
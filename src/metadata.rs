@@ -1,7 +1,49 @@
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use chrono::{DateTime, Utc};
 use reqwest::header::HeaderMap;
 use crate::debug;
+use crate::restore::RestoreStatus;
+use serde::{Deserialize, Serialize};
+
+/// 文件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectType {
+    /// 简单上传的文件
+    Normal,
+    /// 追加上传的文件
+    Appendable,
+    /// 分片上传的文件
+    Multipart,
+    /// 软链接
+    Symlink,
+}
+
+impl Display for ObjectType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectType::Normal => write!(f, "Normal"),
+            ObjectType::Appendable => write!(f, "Appendable"),
+            ObjectType::Multipart => write!(f, "Multipart"),
+            ObjectType::Symlink => write!(f, "Symlink"),
+        }
+    }
+}
+
+impl FromStr for ObjectType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Normal" => Ok(ObjectType::Normal),
+            "Appendable" => Ok(ObjectType::Appendable),
+            "Multipart" => Ok(ObjectType::Multipart),
+            "Symlink" => Ok(ObjectType::Symlink),
+            _ => Err(format!("unknown object type: {}", s)),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct ObjectMetadata {
@@ -20,8 +62,8 @@ impl ObjectMetadata {
         for (key, value) in headers.iter() {
             let key = key.as_str().to_string().to_lowercase();
             let value = value.to_str().unwrap().to_string();
-            if key.starts_with("x-oss-meta-") {
-                user_metadata.insert(key[11..].to_string(), value);
+            if let Some(user_key) = key.strip_prefix("x-oss-meta-") {
+                user_metadata.insert(user_key.to_string(), value);
             } else if key == "etag" {
                 let value = value.trim_matches('"').to_string();
                 metadata.insert("etag".to_string(), value);
@@ -42,7 +84,7 @@ impl ObjectMetadata {
             return None;
         }
         let result = chrono::DateTime::parse_from_rfc2822(val.unwrap()).map(|dt| dt.with_timezone(&chrono::Utc));
-        return match result {
+        match result {
             Ok(date) => {
                 Some(date)
             }
@@ -50,7 +92,7 @@ impl ObjectMetadata {
                 debug!("Last modified parsed failed.{}", e);
                 None
             }
-        };
+        }
     }
 
     pub fn expiration_time(&self) -> Option<DateTime<Utc>> {
@@ -60,7 +102,7 @@ impl ObjectMetadata {
             return None;
         }
         let result = chrono::DateTime::parse_from_rfc2822(val.unwrap()).map(|dt| dt.with_timezone(&Utc));
-        return match result {
+        match result {
             Ok(date) => {
                 Some(date)
             }
@@ -68,7 +110,7 @@ impl ObjectMetadata {
                 debug!("Expiration time parsed failed.{}", e);
                 None
             }
-        };
+        }
     }
     pub fn content_md5(&self) -> Option<String> {
         self.metadata.get("content-md5").map(|s| s.to_string())
@@ -97,7 +139,43 @@ impl ObjectMetadata {
     pub fn server_side_encryption(&self) -> Option<String> {
         self.metadata.get("x-oss-server-side-encryption").map(|s| s.to_string())
     }
-    pub fn object_type(&self) -> Option<String> {
-        self.metadata.get("x-oss-object-type").map(|s| s.to_string())
+    /// 使用KMS加密时的密钥ID
+    pub fn server_side_encryption_key_id(&self) -> Option<String> {
+        self.metadata.get("x-oss-server-side-encryption-key-id").map(|s| s.to_string())
+    }
+    /// 使用KMS加密时的数据加密算法，例如`SM4`
+    pub fn server_side_data_encryption(&self) -> Option<String> {
+        self.metadata.get("x-oss-server-side-data-encryption").map(|s| s.to_string())
+    }
+    pub fn object_type(&self) -> Option<ObjectType> {
+        self.metadata.get("x-oss-object-type").and_then(|s| s.parse().ok())
+    }
+    /// 归档类文件的解冻状态，未发起解冻时为None
+    pub fn restore_status(&self) -> Option<RestoreStatus> {
+        self.metadata.get("x-oss-restore").and_then(|s| RestoreStatus::parse(s))
+    }
+    /// 文件的标签数量
+    pub fn tagging_count(&self) -> Option<u32> {
+        self.metadata.get("x-oss-tagging-count").and_then(|s| s.parse().ok())
+    }
+    /// 开启版本控制后返回文件的版本ID
+    pub fn version_id(&self) -> Option<String> {
+        self.metadata.get("x-oss-version-id").map(|s| s.to_string())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderMap;
+    use crate::metadata::{ObjectMetadata, ObjectType};
+
+    #[test]
+    fn test_object_type() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-oss-object-type", "Symlink".parse().unwrap());
+        assert_eq!(ObjectMetadata::new(&headers).object_type(), Some(ObjectType::Symlink));
+        headers.insert("x-oss-object-type", "Unknown".parse().unwrap());
+        assert_eq!(ObjectMetadata::new(&headers).object_type(), None);
+        assert_eq!(ObjectType::Appendable.to_string(), "Appendable");
+    }
+}
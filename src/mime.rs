@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+/// 根据key的扩展名和文件头推断Content-Type，未调用`with_content_type`的上传请求会使用推断结果
+///
+/// 优先级：自定义映射 > 扩展名 > 文件头特征
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::client::Client;
+/// use aliyun_oss_rust_sdk::mime::MimeResolver;
+/// let resolver = MimeResolver::new()
+///     .with_mapping("log", "text/plain");
+/// assert_eq!(resolver.resolve("/logs/app.log", None).as_deref(), Some("text/plain"));
+/// assert_eq!(resolver.resolve("/upload/avatar", Some(b"\x89PNG\r\n\x1a\n")).as_deref(), Some("image/png"));
+/// let client = Client::new("my_key_id", "my_key_secret", "oss-cn-shanghai.aliyuncs.com")
+///     .with_mime_resolver(resolver);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MimeResolver {
+    mappings: HashMap<String, String>,
+}
+
+/// 文件头特征，`offset`处的字节与`magic`相同
+struct Signature {
+    offset: usize,
+    magic: &'static [u8],
+    mime: &'static str,
+}
+
+const SIGNATURES: &[Signature] = &[
+    Signature { offset: 0, magic: b"\x89PNG\r\n\x1a\n", mime: "image/png" },
+    Signature { offset: 0, magic: b"\xff\xd8\xff", mime: "image/jpeg" },
+    Signature { offset: 0, magic: b"GIF87a", mime: "image/gif" },
+    Signature { offset: 0, magic: b"GIF89a", mime: "image/gif" },
+    Signature { offset: 8, magic: b"WEBP", mime: "image/webp" },
+    Signature { offset: 0, magic: b"BM", mime: "image/bmp" },
+    Signature { offset: 0, magic: b"II*\x00", mime: "image/tiff" },
+    Signature { offset: 0, magic: b"MM\x00*", mime: "image/tiff" },
+    Signature { offset: 0, magic: b"\x00\x00\x01\x00", mime: "image/x-icon" },
+    Signature { offset: 4, magic: b"ftypavif", mime: "image/avif" },
+    Signature { offset: 4, magic: b"ftypheic", mime: "image/heic" },
+    Signature { offset: 4, magic: b"ftyp", mime: "video/mp4" },
+    Signature { offset: 0, magic: b"\x1a\x45\xdf\xa3", mime: "video/webm" },
+    Signature { offset: 8, magic: b"WAVE", mime: "audio/wav" },
+    Signature { offset: 8, magic: b"AVI ", mime: "video/x-msvideo" },
+    Signature { offset: 0, magic: b"ID3", mime: "audio/mpeg" },
+    Signature { offset: 0, magic: b"OggS", mime: "audio/ogg" },
+    Signature { offset: 0, magic: b"fLaC", mime: "audio/flac" },
+    Signature { offset: 0, magic: b"%PDF-", mime: "application/pdf" },
+    Signature { offset: 0, magic: b"PK\x03\x04", mime: "application/zip" },
+    Signature { offset: 0, magic: b"\x1f\x8b", mime: "application/gzip" },
+    Signature { offset: 0, magic: b"7z\xbc\xaf\x27\x1c", mime: "application/x-7z-compressed" },
+    Signature { offset: 0, magic: b"Rar!\x1a\x07", mime: "application/vnd.rar" },
+    Signature { offset: 0, magic: b"\x00asm", mime: "application/wasm" },
+    Signature { offset: 0, magic: b"wOFF", mime: "font/woff" },
+    Signature { offset: 0, magic: b"wOF2", mime: "font/woff2" },
+];
+
+impl MimeResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 自定义扩展名(不含`.`，不区分大小写)对应的Content-Type
+    pub fn with_mapping<E: AsRef<str>, M: AsRef<str>>(mut self, extension: E, mime: M) -> Self {
+        self.mappings.insert(
+            extension.as_ref().trim_start_matches('.').to_lowercase(),
+            mime.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// 推断Content-Type，`head`为文件开头的数据(至少16字节效果较好)，无法推断时返回None
+    pub fn resolve(&self, key: &str, head: Option<&[u8]>) -> Option<String> {
+        let file_name = key.rsplit('/').next().unwrap_or(key);
+        let extension = file_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_lowercase())
+            .filter(|ext| !ext.is_empty());
+        if let Some(extension) = extension {
+            if let Some(mime) = self.mappings.get(&extension) {
+                return Some(mime.to_string());
+            }
+            if let Some(mime) = mime_guess::from_ext(&extension).first_raw() {
+                return Some(mime.to_string());
+            }
+        }
+        head.and_then(sniff).map(|mime| mime.to_string())
+    }
+}
+
+/// 根据文件头特征推断Content-Type
+pub fn sniff(head: &[u8]) -> Option<&'static str> {
+    SIGNATURES
+        .iter()
+        .find(|s| head.len() >= s.offset + s.magic.len() && &head[s.offset..s.offset + s.magic.len()] == s.magic)
+        .map(|s| s.mime)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mime::{sniff, MimeResolver};
+
+    #[test]
+    fn test_sniff() {
+        assert_eq!(sniff(b"\xff\xd8\xff\xe0\x00\x10JFIF"), Some("image/jpeg"));
+        assert_eq!(sniff(b"RIFF\x24\x00\x00\x00WEBPVP8 "), Some("image/webp"));
+        assert_eq!(sniff(b"RIFF\x24\x00\x00\x00WAVEfmt "), Some("audio/wav"));
+        assert_eq!(sniff(b"\x00\x00\x00\x20ftypisom"), Some("video/mp4"));
+        assert_eq!(sniff(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(sniff(b"hello"), None);
+        assert_eq!(sniff(b""), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let resolver = MimeResolver::new().with_mapping(".MD", "text/markdown; charset=utf-8");
+        assert_eq!(resolver.resolve("/a/b/photo.JPG", None).as_deref(), Some("image/jpeg"));
+        assert_eq!(resolver.resolve("/docs/readme.md", None).as_deref(), Some("text/markdown; charset=utf-8"));
+        assert_eq!(resolver.resolve("report.pdf", Some(b"\x89PNG\r\n\x1a\n")).as_deref(), Some("application/pdf"));
+        assert_eq!(resolver.resolve("/upload/1234", Some(b"%PDF-1.4")).as_deref(), Some("application/pdf"));
+        assert_eq!(resolver.resolve("/v1.2/blob", Some(b"GIF89a")).as_deref(), Some("image/gif"));
+        assert_eq!(resolver.resolve("/upload/1234.unknownext", None), None);
+        assert_eq!(resolver.resolve("/upload/1234", Some(b"plain")), None);
+    }
+}
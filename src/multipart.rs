@@ -0,0 +1,146 @@
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use crate::crc64;
+
+/// 追加上传的结果
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppendObjectResult {
+    /// 下一次追加的位置，即当前文件长度
+    pub next_position: u64,
+    /// 追加后整个文件的CRC64，作为下一次追加的`init_crc64`
+    pub crc64: Option<u64>,
+}
+
+impl AppendObjectResult {
+    pub(crate) fn new(headers: &HeaderMap) -> Self {
+        let get = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+        };
+        Self {
+            next_position: get("x-oss-next-append-position").unwrap_or_default(),
+            crc64: get("x-oss-hash-crc64ecma"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "InitiateMultipartUploadResult", rename_all = "PascalCase")]
+pub(crate) struct InitiateMultipartUploadResult {
+    pub(crate) upload_id: String,
+}
+
+/// 已上传的分片，完成分片上传时按`part_number`排序提交
+#[derive(Debug, Clone)]
+pub struct UploadPart {
+    /// 1~10000
+    pub part_number: u32,
+    pub etag: String,
+    pub size: u64,
+    pub crc64: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename = "CompleteMultipartUpload")]
+pub(crate) struct CompleteMultipartUpload {
+    #[serde(rename = "Part")]
+    pub(crate) parts: Vec<CompletePart>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct CompletePart {
+    pub(crate) part_number: u32,
+    #[serde(rename = "ETag")]
+    pub(crate) etag: String,
+}
+
+impl CompleteMultipartUpload {
+    /// 按分片号排序，返回请求体和合并后的CRC64
+    pub(crate) fn new(parts: &[UploadPart]) -> (Self, u64) {
+        let mut parts = parts.to_vec();
+        parts.sort_by_key(|p| p.part_number);
+        let crc = parts.iter().fold(0, |crc, p| crc64::combine(crc, p.crc64, p.size));
+        let parts = parts
+            .into_iter()
+            .map(|p| CompletePart {
+                part_number: p.part_number,
+                etag: format!("\"{}\"", p.etag),
+            })
+            .collect();
+        (Self { parts }, crc)
+    }
+}
+
+/// 完成分片上传的结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "CompleteMultipartUploadResult", rename_all = "PascalCase", default)]
+pub struct CompleteMultipartUploadResult {
+    pub location: String,
+    pub bucket: String,
+    pub key: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    /// 设置了上传回调时为回调服务器的响应内容，此时其它字段只有`key`和`etag`
+    #[serde(skip)]
+    pub callback_body: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderMap;
+    use crate::crc64::crc64;
+    use crate::multipart::{AppendObjectResult, CompleteMultipartUpload, CompleteMultipartUploadResult, InitiateMultipartUploadResult, UploadPart};
+
+    #[test]
+    fn test_complete_multipart_upload() {
+        let part = |part_number: u32, data: &[u8]| UploadPart {
+            part_number,
+            etag: format!("ETAG{}", part_number),
+            size: data.len() as u64,
+            crc64: crc64(data),
+        };
+        let parts = vec![part(2, b" world"), part(1, b"hello")];
+        let (body, crc) = CompleteMultipartUpload::new(&parts);
+        assert_eq!(crc, crc64(b"hello world"));
+        assert_eq!(
+            quick_xml::se::to_string(&body).unwrap(),
+            "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>&quot;ETAG1&quot;</ETag></Part>\
+<Part><PartNumber>2</PartNumber><ETag>&quot;ETAG2&quot;</ETag></Part></CompleteMultipartUpload>"
+        );
+    }
+
+    #[test]
+    fn test_multipart_result_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<InitiateMultipartUploadResult xmlns="http://doc.oss-cn-hangzhou.aliyuncs.com">
+  <Bucket>oss-example</Bucket>
+  <Key>multipart.data</Key>
+  <UploadId>0004B9894A22E5B1888A1E29F823****</UploadId>
+</InitiateMultipartUploadResult>"#;
+        let result: InitiateMultipartUploadResult = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(result.upload_id, "0004B9894A22E5B1888A1E29F823****");
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CompleteMultipartUploadResult xmlns="http://doc.oss-cn-hangzhou.aliyuncs.com">
+  <Location>http://oss-example.oss-cn-hangzhou.aliyuncs.com/multipart.data</Location>
+  <Bucket>oss-example</Bucket>
+  <Key>multipart.data</Key>
+  <ETag>"097DE458AD02B5F89F9D0530231876****"</ETag>
+</CompleteMultipartUploadResult>"#;
+        let result: CompleteMultipartUploadResult = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(result.key, "multipart.data");
+    }
+
+    #[test]
+    fn test_append_object_result() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-oss-next-append-position", "1717".parse().unwrap());
+        headers.insert("x-oss-hash-crc64ecma", "14741617095266562575".parse().unwrap());
+        let result = AppendObjectResult::new(&headers);
+        assert_eq!(result.next_position, 1717);
+        assert_eq!(result.crc64, Some(14741617095266562575));
+    }
+}
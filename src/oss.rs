@@ -1,14 +1,14 @@
-use crate::auth::AuthAPI;
+use crate::auth::{AuthAPI, SignVersion, V4_UNSIGNED_PAYLOAD};
+use crate::client::Client;
 use crate::request::RequestBuilder;
 use chrono::{DateTime, Utc};
-use reqwest::header::{HeaderMap, InvalidHeaderValue, AUTHORIZATION, CONTENT_TYPE, DATE};
+use crate::error::OssError;
+use reqwest::header::{HeaderMap, HeaderName, AUTHORIZATION, CONTENT_TYPE, DATE};
 
-/// OSS配置
+/// OSS配置(bucket句柄)
 #[derive(Debug, Clone)]
 pub struct OSS {
-    key_id: String,
-    key_secret: String,
-    endpoint: String,
+    client: Client,
     bucket: String,
 }
 
@@ -46,25 +46,25 @@ pub trait API {
 
 impl OSSInfo for OSS {
     fn endpoint(&self) -> String {
-        self.endpoint.clone()
+        self.client.endpoint()
     }
     fn bucket(&self) -> String {
         self.bucket.clone()
     }
 
     fn key_id(&self) -> String {
-        self.key_id.clone()
+        self.client.key_id()
     }
 
     fn key_secret(&self) -> String {
-        self.key_secret.clone()
+        self.client.key_secret()
     }
 }
 
 impl API for OSS {
     fn format_oss_resource_str<S: AsRef<str>>(&self, bucket: S, key: S) -> String {
         let bucket = bucket.as_ref();
-        if bucket == "" {
+        if bucket.is_empty() {
             format!("/{}", bucket)
         } else {
             format!("/{}{}", bucket, key.as_ref())
@@ -72,13 +72,10 @@ impl API for OSS {
     }
 }
 
-impl<'a> OSS {
+impl OSS {
     pub fn from_env() -> Self {
-        let key_id = std::env::var("OSS_KEY_ID").expect("OSS_KEY_ID not found");
-        let key_secret = std::env::var("OSS_KEY_SECRET").expect("OSS_KEY_SECRET not found");
-        let endpoint = std::env::var("OSS_ENDPOINT").expect("OSS_ENDPOINT not found");
         let bucket = std::env::var("OSS_BUCKET").expect("OSS_BUCKET not found");
-        OSS::new(key_id, key_secret, endpoint, bucket)
+        Client::from_env().bucket(bucket)
     }
 
     #[cfg(feature = "debug-print")]
@@ -92,14 +89,21 @@ impl<'a> OSS {
     #[cfg(not(feature = "debug-print"))]
     pub fn open_debug(&self) {}
     pub fn new<S: Into<String>>(key_id: S, key_secret: S, endpoint: S, bucket: S) -> Self {
+        Client::new(key_id, key_secret, endpoint).bucket(bucket)
+    }
+
+    pub(crate) fn from_client<S: Into<String>>(client: Client, bucket: S) -> Self {
         OSS {
-            key_id: key_id.into(),
-            key_secret: key_secret.into(),
-            endpoint: endpoint.into(),
+            client,
             bucket: bucket.into(),
         }
     }
 
+    /// 当前bucket所使用的客户端，可用于获取其它bucket的句柄
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
     pub fn format_host<S: AsRef<str>>(&self, bucket: S, key: S, build: &RequestBuilder) -> String {
         let key = if key.as_ref().starts_with("/") {
             key.as_ref().to_string()
@@ -108,6 +112,13 @@ impl<'a> OSS {
         };
         if let Some(cdn) = &build.cdn {
             format!("{}{}", cdn, key,)
+        } else if bucket.as_ref().is_empty() {
+            //服务级别请求(例如列举bucket)直接访问endpoint
+            if self.endpoint().starts_with("http://") || self.endpoint().starts_with("https://") {
+                format!("{}{}", self.endpoint(), key)
+            } else {
+                format!("http://{}{}", self.endpoint(), key)
+            }
         } else {
             if self.endpoint().starts_with("https") {
                 format!(
@@ -127,22 +138,87 @@ impl<'a> OSS {
         }
     }
 
+    /// 开启CRC校验且响应带有`x-oss-hash-crc64ecma`时，与本地计算的CRC64比较
+    pub(crate) fn verify_crc64(&self, headers: &HeaderMap, actual: u64) -> Result<(), OssError> {
+        if !self.client.crc_check() {
+            return Ok(());
+        }
+        let expected = match headers.get("x-oss-hash-crc64ecma").and_then(|v| v.to_str().ok()) {
+            Some(expected) => expected,
+            None => return Ok(()),
+        };
+        if expected.parse::<u64>().ok() != Some(actual) {
+            return Err(OssError::IntegrityMismatch {
+                algorithm: "crc64ecma".to_string(),
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// 开启了`MimeResolver`且未手动设置Content-Type时，按`names`依次推断，都失败时使用文件头
+    pub(crate) fn resolve_content_type(&self, names: &[&str], head: Option<&[u8]>, build: RequestBuilder) -> RequestBuilder {
+        let resolver = match self.client.mime_resolver() {
+            Some(resolver) if build.content_type.is_none() => resolver,
+            _ => return build,
+        };
+        let content_type = names
+            .iter()
+            .find_map(|name| resolver.resolve(name, None))
+            .or_else(|| head.and_then(|head| resolver.resolve("", Some(head))));
+        match content_type {
+            Some(content_type) => build.with_content_type(content_type),
+            None => build,
+        }
+    }
+
+    /// 复制源，格式为`/bucket/key`，key需要urlencode
+    pub(crate) fn copy_source<B: AsRef<str>, S: AsRef<str>>(&self, bucket: B, key: S) -> String {
+        format!("/{}{}", bucket.as_ref(), self.key_urlencode(self.format_key(key)))
+    }
+
     pub fn build_request<S: AsRef<str>>(
         &self,
         key: S,
         build: RequestBuilder,
-    ) -> Result<(String, HeaderMap), InvalidHeaderValue> {
+    ) -> Result<(String, HeaderMap), OssError> {
         let mut build = build.clone();
-        let host = self.format_host(self.bucket(), key.as_ref().to_string(), &build);
+        let mut host = self.format_host(self.bucket(), key.as_ref().to_string(), &build);
+        let query = build.query_string();
+        if !query.is_empty() {
+            host = format!("{}?{}", host, query);
+        }
         let mut header = HeaderMap::new();
-        let date = self.date();
+        let now = Utc::now();
+        let date = now.format("%a, %d %b %Y %T GMT").to_string();
         header.insert(DATE, date.parse()?);
         build.headers.insert(DATE.to_string(), date);
         let key = key.as_ref();
-        let authorization = self.oss_sign(key, &build);
+        let authorization = match self.client.sign_version() {
+            SignVersion::V1 => self.oss_sign(key, &build),
+            SignVersion::V4 => {
+                build.oss_headers.insert("x-oss-date".to_string(), now.format("%Y%m%dT%H%M%SZ").to_string());
+                build.oss_headers.insert("x-oss-content-sha256".to_string(), V4_UNSIGNED_PAYLOAD.to_string());
+                self.oss_sign_v4(key, &build, now)?
+            }
+        };
         if let Some(content_type) = build.content_type {
             header.insert(CONTENT_TYPE, content_type.parse()?);
         }
+        if let Some(content_md5) = build.content_md5 {
+            header.insert("content-md5", content_md5.parse()?);
+        }
+        for (k, v) in build.headers.iter().filter(|(k, _)| !k.eq_ignore_ascii_case(DATE.as_str())) {
+            let name = HeaderName::from_bytes(k.to_lowercase().as_bytes())
+                .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
+            header.insert(name, v.parse()?);
+        }
+        for (k, v) in build.oss_headers.iter() {
+            let name = HeaderName::from_bytes(k.to_lowercase().as_bytes())
+                .map_err(|e| OssError::Err(format!("build request error: {}", e)))?;
+            header.insert(name, v.parse()?);
+        }
         header.insert(AUTHORIZATION, authorization.parse()?);
         Ok((host, header))
     }
@@ -154,7 +230,12 @@ impl<'a> OSS {
 
 #[cfg(test)]
 mod tests {
+    use reqwest::header::HeaderMap;
+    use crate::client::Client;
+    use crate::crc64::crc64;
     use crate::error::OssError;
+    use crate::mime::MimeResolver;
+    use crate::request::RequestBuilder;
     use std::io::Read;
 
     fn open_file(file_name: &str) -> Result<String, OssError> {
@@ -168,4 +249,39 @@ mod tests {
     fn test_read_file() {
         open_file("a").unwrap();
     }
+
+    #[test]
+    fn test_resolve_content_type() {
+        let client = Client::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com");
+        let build = client.bucket("bucket").resolve_content_type(&["/a.png"], None, RequestBuilder::new());
+        assert_eq!(build.content_type, None);
+        let oss = client.with_mime_resolver(MimeResolver::new()).bucket("bucket");
+        let build = oss.resolve_content_type(&["/upload/1", "./a.pdf"], None, RequestBuilder::new());
+        assert_eq!(build.content_type.as_deref(), Some("application/pdf"));
+        let build = oss.resolve_content_type(&["/upload/1"], Some(b"GIF89a"), RequestBuilder::new());
+        assert_eq!(build.content_type.as_deref(), Some("image/gif"));
+        let build = RequestBuilder::new().with_content_type("text/plain");
+        let build = oss.resolve_content_type(&["/a.png"], None, build);
+        assert_eq!(build.content_type.as_deref(), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_verify_crc64() {
+        let oss = Client::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com").bucket("bucket");
+        assert!(oss.verify_crc64(&HeaderMap::new(), 1).is_ok());
+        let mut headers = HeaderMap::new();
+        headers.insert("x-oss-hash-crc64ecma", "11051210869376104954".parse().unwrap());
+        assert!(oss.verify_crc64(&headers, crc64(b"123456789")).is_ok());
+        match oss.verify_crc64(&headers, 0) {
+            Err(OssError::IntegrityMismatch { expected, actual, .. }) => {
+                assert_eq!(expected, "11051210869376104954");
+                assert_eq!(actual, "0");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        let oss = Client::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com")
+            .with_crc_check(false)
+            .bucket("bucket");
+        assert!(oss.verify_crc64(&headers, 0).is_ok());
+    }
 }
@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use serde_json::{json, Value};
+use crate::auth::{hmac_sha256, v4_scope, v4_signing_key, SignVersion, V4_ALGORITHM};
+use crate::callback::Callback;
+use crate::debug;
+use crate::error::OssError;
+use crate::oss::{OSSInfo, OSS};
+use crate::request::Seconds;
+use crate::util;
+
+/// PostObject表单上传的policy构建器，签名后得到浏览器需要提交的表单字段
+///
+/// 字段名不需要带`$`前缀，`with_eq`等固定取值的条件会同时加入表单字段
+///
+/// # 使用例子
+///
+/// ```rust
+/// use aliyun_oss_rust_sdk::oss::OSS;
+/// use aliyun_oss_rust_sdk::policy::PostPolicy;
+/// let oss = OSS::from_env();
+/// let policy = PostPolicy::new()
+///     .with_expire(60 * 60)//1个小时过期
+///     .with_key_prefix("upload/mydir/")//只允许上传到该目录
+///     .with_content_length_range(1, 100 * 1024 * 1024)//1B~100MB
+///     .with_in("content-type", ["image/png", "image/jpeg"])
+///     .with_meta("uid", "1001")
+///     .with_success_action_status(201);
+/// let form = oss.sign_post_policy(&policy).unwrap();
+/// //浏览器向form.host提交multipart/form-data，
+/// //包含form.fields中的全部字段，以及key、Content-Type和最后的file字段
+/// println!("host: {}, fields: {:?}", form.host, form.fields);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    expire: Seconds,
+    conditions: Vec<Value>,
+    fields: BTreeMap<String, String>,
+    sign_version: Option<SignVersion>,
+}
+
+impl Default for PostPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PostPolicy {
+    pub fn new() -> Self {
+        Self {
+            expire: 60,//60秒
+            conditions: vec![],
+            fields: BTreeMap::new(),
+            sign_version: None,
+        }
+    }
+
+    /// 过期时间(秒)，从签名时开始计算
+    pub fn with_expire(mut self, expire: Seconds) -> Self {
+        self.expire = expire;
+        self
+    }
+
+    /// 签名版本，默认使用客户端的签名版本
+    pub fn with_sign_version(mut self, sign_version: SignVersion) -> Self {
+        self.sign_version = Some(sign_version);
+        self
+    }
+
+    /// 字段必须等于`value`，并作为表单字段返回
+    pub fn with_eq<F: AsRef<str>, V: AsRef<str>>(mut self, field: F, value: V) -> Self {
+        let field = field.as_ref().trim_start_matches('$');
+        self.conditions.push(json!(["eq", format!("${}", field), value.as_ref()]));
+        self.fields.insert(field.to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// 字段必须以`prefix`开头，表单字段由浏览器填写
+    pub fn with_starts_with<F: AsRef<str>, P: AsRef<str>>(mut self, field: F, prefix: P) -> Self {
+        let field = field.as_ref().trim_start_matches('$');
+        self.conditions.push(json!(["starts-with", format!("${}", field), prefix.as_ref()]));
+        self
+    }
+
+    /// 字段必须是`values`之一，表单字段由浏览器填写
+    pub fn with_in<F, I, V>(mut self, field: F, values: I) -> Self
+    where
+        F: AsRef<str>,
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let field = field.as_ref().trim_start_matches('$');
+        let values = values.into_iter().map(|v| v.as_ref().to_string()).collect::<Vec<_>>();
+        self.conditions.push(json!(["in", format!("${}", field), values]));
+        self
+    }
+
+    /// 字段不能是`values`中的任何一个
+    pub fn with_not_in<F, I, V>(mut self, field: F, values: I) -> Self
+    where
+        F: AsRef<str>,
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let field = field.as_ref().trim_start_matches('$');
+        let values = values.into_iter().map(|v| v.as_ref().to_string()).collect::<Vec<_>>();
+        self.conditions.push(json!(["not-in", format!("${}", field), values]));
+        self
+    }
+
+    /// 上传文件大小范围(字节)
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.conditions.push(json!(["content-length-range", min, max]));
+        self
+    }
+
+    /// 只允许上传到固定的key
+    pub fn with_key<S: AsRef<str>>(self, key: S) -> Self {
+        self.with_eq("key", key.as_ref().trim_start_matches('/'))
+    }
+
+    /// 只允许上传到以`prefix`开头的key，例如`upload/mydir/`
+    pub fn with_key_prefix<S: AsRef<str>>(self, prefix: S) -> Self {
+        self.with_starts_with("key", prefix.as_ref().trim_start_matches('/'))
+    }
+
+    /// 上传成功后返回的状态码，可选200、201、204，默认204
+    pub fn with_success_action_status(self, status: u16) -> Self {
+        self.with_eq("success_action_status", status.to_string())
+    }
+
+    /// 上传成功后浏览器跳转的地址
+    pub fn with_success_action_redirect<S: AsRef<str>>(self, url: S) -> Self {
+        self.with_eq("success_action_redirect", url)
+    }
+
+    /// 自定义元数据`x-oss-meta-{name}`
+    pub fn with_meta<K: AsRef<str>, V: AsRef<str>>(self, name: K, value: V) -> Self {
+        self.with_eq(format!("x-oss-meta-{}", name.as_ref().to_lowercase()), value)
+    }
+
+    /// 上传回调，自定义变量作为`x:`开头的表单字段
+    pub fn with_callback(self, callback: &Callback) -> Self {
+        let policy = self.with_eq("callback", callback.encode());
+        callback
+            .vars()
+            .iter()
+            .fold(policy, |policy, (name, value)| policy.with_eq(name, value))
+    }
+
+    /// 使用STS临时凭证签名时需要带上的安全令牌
+    pub fn with_security_token<S: AsRef<str>>(mut self, token: S) -> Self {
+        self.conditions.push(json!({"x-oss-security-token": token.as_ref()}));
+        self.fields.insert("x-oss-security-token".to_string(), token.as_ref().to_string());
+        self
+    }
+}
+
+/// 签名后的PostObject表单，浏览器向`host`提交`fields`中的全部字段，`file`字段必须放在最后
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostPolicyForm {
+    pub host: String,
+    pub fields: BTreeMap<String, String>,
+}
+
+/// PostObject上传的结果
+#[derive(Debug, Clone, Default)]
+pub struct PostObjectResult {
+    /// 状态码，由`success_action_status`决定，默认204
+    pub status: u16,
+    pub etag: Option<String>,
+    pub version_id: Option<String>,
+    /// 响应内容，`success_action_status`为200/201或设置了回调时不为空
+    pub body: Vec<u8>,
+}
+
+impl PostObjectResult {
+    pub(crate) fn new(status: u16, headers: &HeaderMap, body: Vec<u8>) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(|v| v.to_string());
+        Self {
+            status,
+            etag: get("etag").map(|v| v.trim_matches('"').to_string()),
+            version_id: get("x-oss-version-id"),
+            body,
+        }
+    }
+}
+
+impl OSS {
+    /// 签名PostObject表单
+    ///
+    /// # 使用例子
+    ///
+    /// ```rust
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::policy::PostPolicy;
+    /// let oss = OSS::from_env();
+    /// let form = oss.sign_post_policy(&PostPolicy::new().with_key("upload/avatar.png")).unwrap();
+    /// println!("policy: {}", form.fields["policy"]);
+    /// ```
+    pub fn sign_post_policy(&self, policy: &PostPolicy) -> Result<PostPolicyForm, OssError> {
+        self.sign_post_policy_at(policy, Utc::now())
+    }
+
+    pub(crate) fn sign_post_policy_at(
+        &self,
+        policy: &PostPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<PostPolicyForm, OssError> {
+        let sign_version = policy.sign_version.unwrap_or(self.client().sign_version());
+        let mut conditions = vec![json!({"bucket": self.bucket()})];
+        conditions.extend(policy.conditions.iter().cloned());
+        let mut fields = policy.fields.clone();
+        let region = match sign_version {
+            SignVersion::V1 => None,
+            SignVersion::V4 => {
+                let region = self
+                    .client()
+                    .region()
+                    .ok_or_else(|| OssError::Err("V4 signature requires a region".to_string()))?;
+                let v4_fields = [
+                    ("x-oss-signature-version", V4_ALGORITHM.to_string()),
+                    ("x-oss-credential", format!("{}/{}", self.key_id(), v4_scope(&now, region.id()))),
+                    ("x-oss-date", now.format("%Y%m%dT%H%M%SZ").to_string()),
+                ];
+                for (name, value) in v4_fields {
+                    conditions.push(json!({ name: value }));
+                    fields.insert(name.to_string(), value);
+                }
+                Some(region)
+            }
+        };
+        let expiration = (now + Duration::seconds(policy.expire))
+            .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+            .to_string();
+        let policy_json = json!({
+            "expiration": expiration,
+            "conditions": conditions,
+        })
+        .to_string();
+        debug!("oss log: policy json: {}", policy_json);
+        let base64_policy = util::base64_encode(policy_json.as_bytes());
+        match region {
+            None => {
+                let mut hasher: Hmac<sha1::Sha1> = Hmac::new_from_slice(self.key_secret().as_bytes())
+                    .map_err(|_| OssError::Err("Hmac new from slice error".to_string()))?;
+                hasher.update(base64_policy.as_bytes());
+                let signature = util::base64_encode(hasher.finalize().into_bytes());
+                fields.insert("OSSAccessKeyId".to_string(), self.key_id().to_string());
+                fields.insert("Signature".to_string(), signature);
+            }
+            Some(region) => {
+                let signing_key = v4_signing_key(self.key_secret().as_str(), &now, region.id());
+                let signature = util::hex(hmac_sha256(&signing_key, base64_policy.as_bytes()));
+                fields.insert("x-oss-signature".to_string(), signature);
+            }
+        }
+        fields.insert("policy".to_string(), base64_policy);
+        Ok(PostPolicyForm {
+            host: self.post_host(),
+            fields,
+        })
+    }
+
+    /// PostObject的提交地址，endpoint显式指定`http://`时使用http，否则使用https
+    pub(crate) fn post_host(&self) -> String {
+        let endpoint = self.endpoint();
+        match endpoint.strip_prefix("http://") {
+            Some(endpoint) => format!("http://{}.{}", self.bucket(), endpoint),
+            None => format!("https://{}.{}", self.bucket(), endpoint.trim_start_matches("https://")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose;
+    use chrono::{TimeZone, Utc};
+    use hmac::{Hmac, Mac};
+    use serde_json::{json, Value};
+    use crate::auth::{hmac_sha256, v4_signing_key, SignVersion};
+    use crate::callback::{Callback, CallbackVariable};
+    use crate::client::Client;
+    use crate::oss::OSS;
+    use crate::policy::{PostObjectResult, PostPolicy};
+    use crate::region::Region;
+    use crate::util::hex;
+
+    fn decode_policy(policy: &str) -> Value {
+        let json = general_purpose::STANDARD.decode(policy).unwrap();
+        serde_json::from_slice(&json).unwrap()
+    }
+
+    #[test]
+    fn test_sign_post_policy_v1() {
+        let oss = OSS::new("key_id", "key_secret", "https://oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let now = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let policy = PostPolicy::new()
+            .with_expire(3600)
+            .with_key_prefix("/upload/\"dir\"/")
+            .with_content_length_range(1, 1024)
+            .with_in("content-type", ["image/png", "image/jpeg"])
+            .with_not_in("$cache-control", ["no-cache"])
+            .with_meta("UID", "1001")
+            .with_success_action_redirect("https://example.com/done")
+            .with_security_token("sts-token");
+        let form = oss.sign_post_policy_at(&policy, now).unwrap();
+        assert_eq!(form.host, "https://bucket.oss-cn-hangzhou.aliyuncs.com");
+        assert_eq!(
+            decode_policy(&form.fields["policy"]),
+            json!({
+                "expiration": "2024-01-02T04:04:05.000Z",
+                "conditions": [
+                    {"bucket": "bucket"},
+                    ["starts-with", "$key", "upload/\"dir\"/"],
+                    ["content-length-range", 1, 1024],
+                    ["in", "$content-type", ["image/png", "image/jpeg"]],
+                    ["not-in", "$cache-control", ["no-cache"]],
+                    ["eq", "$x-oss-meta-uid", "1001"],
+                    ["eq", "$success_action_redirect", "https://example.com/done"],
+                    {"x-oss-security-token": "sts-token"},
+                ]
+            })
+        );
+        let mut hasher: Hmac<sha1::Sha1> = Hmac::new_from_slice(b"key_secret").unwrap();
+        hasher.update(form.fields["policy"].as_bytes());
+        let signature = general_purpose::STANDARD.encode(hasher.finalize().into_bytes());
+        assert_eq!(form.fields["Signature"], signature);
+        assert_eq!(form.fields["OSSAccessKeyId"], "key_id");
+        assert_eq!(form.fields["x-oss-meta-uid"], "1001");
+        assert_eq!(form.fields["success_action_redirect"], "https://example.com/done");
+        assert_eq!(form.fields["x-oss-security-token"], "sts-token");
+        assert_eq!(form.fields.len(), 6);
+
+        let oss = OSS::new("key_id", "key_secret", "http://127.0.0.1:9000", "bucket");
+        assert_eq!(oss.sign_post_policy_at(&policy, now).unwrap().host, "http://bucket.127.0.0.1:9000");
+    }
+
+    #[test]
+    fn test_sign_post_policy_v4() {
+        let oss = Client::from_region("key_id", "key_secret", Region::new("cn-hangzhou"))
+            .bucket("bucket");
+        let now = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let policy = PostPolicy::new()
+            .with_sign_version(SignVersion::V4)
+            .with_key("/upload/a.txt")
+            .with_success_action_status(201);
+        let form = oss.sign_post_policy_at(&policy, now).unwrap();
+        let credential = "key_id/20240102/cn-hangzhou/oss/aliyun_v4_request";
+        assert_eq!(
+            decode_policy(&form.fields["policy"])["conditions"],
+            json!([
+                {"bucket": "bucket"},
+                ["eq", "$key", "upload/a.txt"],
+                ["eq", "$success_action_status", "201"],
+                {"x-oss-signature-version": "OSS4-HMAC-SHA256"},
+                {"x-oss-credential": credential},
+                {"x-oss-date": "20240102T030405Z"},
+            ])
+        );
+        let signing_key = v4_signing_key("key_secret", &now, "cn-hangzhou");
+        let signature = hex(hmac_sha256(&signing_key, form.fields["policy"].as_bytes()));
+        assert_eq!(form.fields["x-oss-signature"], signature);
+        assert_eq!(form.fields["x-oss-credential"], credential);
+        assert_eq!(form.fields["key"], "upload/a.txt");
+        assert!(!form.fields.contains_key("OSSAccessKeyId"));
+    }
+
+    #[test]
+    fn test_post_object_result() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("etag", "\"5B3C1A2E053D763E1B002CC607C5****\"".parse().unwrap());
+        headers.insert("x-oss-version-id", "CAEQ****".parse().unwrap());
+        let result = PostObjectResult::new(201, &headers, b"<PostResponse/>".to_vec());
+        assert_eq!(result.status, 201);
+        assert_eq!(result.etag.as_deref(), Some("5B3C1A2E053D763E1B002CC607C5****"));
+        assert_eq!(result.version_id.as_deref(), Some("CAEQ****"));
+    }
+
+    #[test]
+    fn test_post_policy_callback() {
+        let oss = OSS::new("key_id", "key_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let callback = Callback::new("https://example.com/callback")
+            .with_field("object", CallbackVariable::Object)
+            .with_var("uid", "1001");
+        let policy = PostPolicy::new().with_callback(&callback);
+        let form = oss.sign_post_policy(&policy).unwrap();
+        assert_eq!(form.fields["callback"], callback.encode());
+        assert_eq!(form.fields["x:uid"], "1001");
+        assert_eq!(
+            decode_policy(&form.fields["policy"])["conditions"][2],
+            json!(["eq", "$x:uid", "1001"])
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+
+/// bucket防盗链配置
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::referer::RefererConfiguration;
+/// let config = RefererConfiguration::new()
+///     .with_allow_empty_referer(false)
+///     .with_referer("https://*.example.com/*")
+///     .with_black_referer("https://bad.example.com/*");
+/// assert!(config.is_allowed(Some("https://cdn.example.com/index.html")));
+/// assert!(!config.is_allowed(Some("https://bad.example.com/index.html")));
+/// assert!(!config.is_allowed(None));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "RefererConfiguration", rename_all = "PascalCase")]
+pub struct RefererConfiguration {
+    /// 是否允许Referer为空的请求
+    pub allow_empty_referer: bool,
+    /// 匹配前是否去掉Referer中的查询参数，默认true
+    #[serde(default = "default_true")]
+    pub allow_truncate_query_string: bool,
+    /// 匹配前是否去掉Referer中的路径(只匹配协议、域名和端口)，默认false，需要`allow_truncate_query_string`为true
+    #[serde(default)]
+    pub truncate_path: bool,
+    /// 白名单，支持`*`(0个或多个字符)和`?`(1个字符)通配符
+    #[serde(rename = "RefererList", default, with = "referer_list")]
+    pub referer_list: Vec<String>,
+    /// 黑名单，优先级高于白名单
+    #[serde(rename = "RefererBlacklist", default, with = "referer_list", skip_serializing_if = "Vec::is_empty")]
+    pub referer_blacklist: Vec<String>,
+}
+
+impl Default for RefererConfiguration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RefererConfiguration {
+    pub fn new() -> Self {
+        Self {
+            allow_empty_referer: true,
+            allow_truncate_query_string: true,
+            truncate_path: false,
+            referer_list: vec![],
+            referer_blacklist: vec![],
+        }
+    }
+    pub fn with_allow_empty_referer(mut self, allow_empty_referer: bool) -> Self {
+        self.allow_empty_referer = allow_empty_referer;
+        self
+    }
+    pub fn with_allow_truncate_query_string(mut self, allow_truncate_query_string: bool) -> Self {
+        self.allow_truncate_query_string = allow_truncate_query_string;
+        self
+    }
+    pub fn with_truncate_path(mut self, truncate_path: bool) -> Self {
+        self.truncate_path = truncate_path;
+        self
+    }
+    pub fn with_referer<S: AsRef<str>>(mut self, referer: S) -> Self {
+        self.referer_list.push(referer.as_ref().to_string());
+        self
+    }
+    pub fn with_black_referer<S: AsRef<str>>(mut self, referer: S) -> Self {
+        self.referer_blacklist.push(referer.as_ref().to_string());
+        self
+    }
+
+    /// 按OSS的规则在本地判断Referer是否允许访问，用于测试黑白名单
+    ///
+    /// 空Referer由`allow_empty_referer`决定；黑名单优先；白名单为空时允许所有非空Referer；
+    /// 不区分大小写，名单中不带协议头的条目同时匹配http和https
+    pub fn is_allowed(&self, referer: Option<&str>) -> bool {
+        let referer = match referer.map(|r| r.trim()).filter(|r| !r.is_empty()) {
+            None => return self.allow_empty_referer,
+            Some(referer) => referer.to_lowercase(),
+        };
+        let referer = self.truncate(referer.as_str());
+        let matches = |patterns: &Vec<String>| {
+            patterns.iter().any(|pattern| {
+                let pattern = pattern.trim().to_lowercase();
+                if pattern.contains("://") {
+                    wildcard_match(pattern.as_str(), referer)
+                } else {
+                    let without_scheme = referer.split_once("://").map(|(_, rest)| rest).unwrap_or(referer);
+                    wildcard_match(pattern.as_str(), without_scheme)
+                }
+            })
+        };
+        if matches(&self.referer_blacklist) {
+            return false;
+        }
+        self.referer_list.is_empty() || matches(&self.referer_list)
+    }
+
+    fn truncate<'a>(&self, referer: &'a str) -> &'a str {
+        if !self.allow_truncate_query_string {
+            return referer;
+        }
+        let referer = referer.split(['?', '#']).next().unwrap_or(referer);
+        if !self.truncate_path {
+            return referer;
+        }
+        let host_start = referer.find("://").map(|i| i + 3).unwrap_or(0);
+        match referer[host_start..].find('/') {
+            Some(i) => &referer[..host_start + i],
+            None => referer,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// `*`匹配0个或多个字符，`?`匹配1个字符
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+mod referer_list {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct RefererList {
+        #[serde(rename = "Referer", default)]
+        referer: Vec<String>,
+    }
+
+    pub fn serialize<S: Serializer>(value: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        RefererList { referer: value.to_vec() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+        Ok(RefererList::deserialize(deserializer)?.referer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::referer::{wildcard_match, RefererConfiguration};
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("http://www.*.com", "http://www.aliyun.com"));
+        assert!(wildcard_match("http://www.?.com", "http://www.a.com"));
+        assert!(!wildcard_match("http://www.?.com", "http://www.ab.com"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("*.example.com*", "https://cdn.example.com/a.png"));
+        assert!(!wildcard_match("https://example.com", "https://example.com/a.png"));
+    }
+
+    #[test]
+    fn test_referer_is_allowed() {
+        let config = RefererConfiguration::new()
+            .with_allow_empty_referer(false)
+            .with_referer("http://www.aliyun.com")
+            .with_referer("https://*.example.com/*")
+            .with_referer("static.?.cn")
+            .with_black_referer("https://bad.example.com/*");
+        assert!(!config.is_allowed(None));
+        assert!(!config.is_allowed(Some("")));
+        assert!(config.is_allowed(Some("http://www.aliyun.com?from=mail")));
+        assert!(config.is_allowed(Some("HTTP://WWW.ALIYUN.COM")));
+        assert!(!config.is_allowed(Some("http://www.aliyun.com/index.html")));
+        assert!(config.is_allowed(Some("https://img.example.com/a.png")));
+        assert!(!config.is_allowed(Some("https://bad.example.com/a.png")));
+        assert!(config.is_allowed(Some("http://static.a.cn")));
+        assert!(config.is_allowed(Some("https://static.b.cn")));
+        assert!(!config.is_allowed(Some("https://static.ab.cn")));
+
+        let config = config.with_truncate_path(true);
+        assert!(config.is_allowed(Some("http://www.aliyun.com/index.html?a=b")));
+
+        let config = RefererConfiguration::new()
+            .with_allow_truncate_query_string(false)
+            .with_referer("http://www.aliyun.com");
+        assert!(config.is_allowed(None));
+        assert!(!config.is_allowed(Some("http://www.aliyun.com?from=mail")));
+    }
+
+    #[test]
+    fn test_referer_edge_cases() {
+        //只有空白字符的Referer按空Referer处理
+        let config = RefererConfiguration::new()
+            .with_allow_empty_referer(false)
+            .with_referer("https://www.example.com");
+        assert!(!config.is_allowed(Some("   ")));
+        assert!(config.with_allow_empty_referer(true).is_allowed(Some("   ")));
+
+        //带协议头的条目不匹配另一种协议
+        let config = RefererConfiguration::new().with_referer("https://www.example.com");
+        assert!(config.is_allowed(Some("https://www.example.com")));
+        assert!(!config.is_allowed(Some("http://www.example.com")));
+        let config = RefererConfiguration::new().with_black_referer("http://www.example.com");
+        assert!(config.is_allowed(Some("https://www.example.com")));
+        assert!(!config.is_allowed(Some("http://www.example.com")));
+
+        //`?`必须匹配恰好1个字符，`*`可以匹配0个字符
+        let config = RefererConfiguration::new().with_referer("https://img?.example.com");
+        assert!(config.is_allowed(Some("https://img1.example.com")));
+        assert!(!config.is_allowed(Some("https://img.example.com")));
+        assert!(!config.is_allowed(Some("https://img12.example.com")));
+        let config = RefererConfiguration::new().with_referer("https://img*.example.com");
+        assert!(config.is_allowed(Some("https://img.example.com")));
+
+        //不带路径通配符时只有去掉路径后才能匹配
+        let config = RefererConfiguration::new().with_referer("https://*.example.com");
+        assert!(!config.is_allowed(Some("https://cdn.example.com/index.html")));
+        let config = config.with_truncate_path(true);
+        assert!(config.is_allowed(Some("https://cdn.example.com/index.html")));
+    }
+
+    #[test]
+    fn test_referer_configuration_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<RefererConfiguration>
+  <AllowEmptyReferer>false</AllowEmptyReferer>
+  <AllowTruncateQueryString>true</AllowTruncateQueryString>
+  <TruncatePath>true</TruncatePath>
+  <RefererList>
+    <Referer>http://www.aliyun.com</Referer>
+    <Referer>https://www.?.aliyuncs.com</Referer>
+  </RefererList>
+  <RefererBlacklist>
+    <Referer>http://www.refuse.com</Referer>
+  </RefererBlacklist>
+</RefererConfiguration>"#;
+        let config: RefererConfiguration = quick_xml::de::from_str(xml).unwrap();
+        assert!(!config.allow_empty_referer);
+        assert!(config.truncate_path);
+        assert_eq!(config.referer_list.len(), 2);
+        assert_eq!(config.referer_blacklist, vec!["http://www.refuse.com"]);
+
+        let config = RefererConfiguration::new().with_referer("http://www.aliyun.com");
+        assert_eq!(
+            quick_xml::se::to_string(&config).unwrap(),
+            "<RefererConfiguration><AllowEmptyReferer>true</AllowEmptyReferer>\
+<AllowTruncateQueryString>true</AllowTruncateQueryString><TruncatePath>false</TruncatePath>\
+<RefererList><Referer>http://www.aliyun.com</Referer></RefererList></RefererConfiguration>"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+
+/// 地域，例如`cn-shanghai`
+///
+/// # 使用例子
+///
+/// ```
+/// use aliyun_oss_rust_sdk::region::{EndpointType, Region};
+/// let region = Region::new("cn-shanghai");
+/// assert_eq!(region.endpoint(EndpointType::Public), "oss-cn-shanghai.aliyuncs.com");
+/// assert_eq!(region.endpoint(EndpointType::Internal), "oss-cn-shanghai-internal.aliyuncs.com");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region(String);
+
+/// endpoint类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EndpointType {
+    /// 外网访问
+    #[default]
+    Public,
+    /// 同地域ECS内网访问，不产生外网流量费用
+    Internal,
+    /// IPv4/IPv6双栈
+    DualStack,
+    /// 全球传输加速
+    Accelerate,
+    /// 非中国内地传输加速
+    OverseasAccelerate,
+}
+
+impl EndpointType {
+    /// 从OSS的endpoint中解析类型，CNAME等非OSS域名返回None
+    pub fn from_endpoint<S: AsRef<str>>(endpoint: S) -> Option<Self> {
+        let host = endpoint_host(endpoint.as_ref());
+        match host {
+            "oss-accelerate.aliyuncs.com" => Some(EndpointType::Accelerate),
+            "oss-accelerate-overseas.aliyuncs.com" => Some(EndpointType::OverseasAccelerate),
+            _ if host.ends_with(".oss.aliyuncs.com") => Some(EndpointType::DualStack),
+            _ if !host.starts_with("oss-") => None,
+            _ if host.ends_with("-internal.aliyuncs.com") => Some(EndpointType::Internal),
+            _ if host.ends_with(".aliyuncs.com") => Some(EndpointType::Public),
+            _ => None,
+        }
+    }
+}
+
+impl Region {
+    /// 同时支持`cn-shanghai`和`oss-cn-shanghai`两种写法
+    pub fn new<S: AsRef<str>>(region: S) -> Self {
+        let region = region.as_ref().trim();
+        Region(region.strip_prefix("oss-").unwrap_or(region).to_string())
+    }
+
+    /// 从endpoint中解析地域，传输加速等不区分地域的endpoint返回None
+    pub fn from_endpoint<S: AsRef<str>>(endpoint: S) -> Option<Self> {
+        let host = endpoint_host(endpoint.as_ref());
+        if let Some(region) = host.strip_suffix(".oss.aliyuncs.com") {
+            return Some(Region::new(region));
+        }
+        let label = host.strip_suffix(".aliyuncs.com")?;
+        let label = label.strip_prefix("oss-")?;
+        if label.starts_with("accelerate") {
+            return None;
+        }
+        Some(Region::new(label.strip_suffix("-internal").unwrap_or(label)))
+    }
+
+    pub fn id(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn endpoint(&self, endpoint_type: EndpointType) -> String {
+        match endpoint_type {
+            EndpointType::Public => format!("oss-{}.aliyuncs.com", self.0),
+            EndpointType::Internal => format!("oss-{}-internal.aliyuncs.com", self.0),
+            EndpointType::DualStack => format!("{}.oss.aliyuncs.com", self.0),
+            EndpointType::Accelerate => "oss-accelerate.aliyuncs.com".to_string(),
+            EndpointType::OverseasAccelerate => "oss-accelerate-overseas.aliyuncs.com".to_string(),
+        }
+    }
+}
+
+/// 去掉endpoint中的协议头
+fn endpoint_host(endpoint: &str) -> &str {
+    endpoint
+        .strip_prefix("https://")
+        .or_else(|| endpoint.strip_prefix("http://"))
+        .unwrap_or(endpoint)
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for Region {
+    fn from(value: &str) -> Self {
+        Region::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::region::{EndpointType, Region};
+
+    #[test]
+    fn test_region_endpoint() {
+        let region = Region::new("oss-cn-hangzhou");
+        assert_eq!(region.id(), "cn-hangzhou");
+        assert_eq!(region.endpoint(EndpointType::Public), "oss-cn-hangzhou.aliyuncs.com");
+        assert_eq!(region.endpoint(EndpointType::Internal), "oss-cn-hangzhou-internal.aliyuncs.com");
+        assert_eq!(region.endpoint(EndpointType::DualStack), "cn-hangzhou.oss.aliyuncs.com");
+        assert_eq!(region.endpoint(EndpointType::Accelerate), "oss-accelerate.aliyuncs.com");
+    }
+
+    #[test]
+    fn test_region_from_endpoint() {
+        for endpoint in [
+            "oss-cn-shanghai.aliyuncs.com",
+            "https://oss-cn-shanghai.aliyuncs.com",
+            "http://oss-cn-shanghai-internal.aliyuncs.com",
+            "cn-shanghai.oss.aliyuncs.com",
+        ] {
+            assert_eq!(Region::from_endpoint(endpoint), Some(Region::new("cn-shanghai")));
+        }
+        assert_eq!(Region::from_endpoint("oss-accelerate.aliyuncs.com"), None);
+        assert_eq!(Region::from_endpoint("127.0.0.1:9000"), None);
+    }
+
+    #[test]
+    fn test_endpoint_type_from_endpoint() {
+        assert_eq!(EndpointType::from_endpoint("https://oss-cn-shanghai.aliyuncs.com"), Some(EndpointType::Public));
+        assert_eq!(EndpointType::from_endpoint("oss-cn-shanghai-internal.aliyuncs.com"), Some(EndpointType::Internal));
+        assert_eq!(EndpointType::from_endpoint("cn-shanghai.oss.aliyuncs.com"), Some(EndpointType::DualStack));
+        assert_eq!(EndpointType::from_endpoint("oss-accelerate.aliyuncs.com"), Some(EndpointType::Accelerate));
+        assert_eq!(
+            EndpointType::from_endpoint("http://oss-accelerate-overseas.aliyuncs.com"),
+            Some(EndpointType::OverseasAccelerate)
+        );
+        assert_eq!(EndpointType::from_endpoint("https://static.example.com"), None);
+    }
+}
@@ -1,5 +1,9 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use crate::acl::Acl;
+use crate::callback::Callback;
+use crate::sse::Sse;
+use crate::tagging::encode_tags;
 
 pub type Seconds = i64;
 
@@ -10,6 +14,7 @@ pub enum RequestType {
     Post,
     Delete,
     Head,
+    Options,
 }
 
 impl Display for RequestType{
@@ -20,6 +25,20 @@ impl Display for RequestType{
             RequestType::Post => write!(f, "POST"),
             RequestType::Delete => write!(f, "DELETE"),
             RequestType::Head => write!(f, "HEAD"),
+            RequestType::Options => write!(f, "OPTIONS"),
+        }
+    }
+}
+
+impl From<RequestType> for reqwest::Method {
+    fn from(value: RequestType) -> Self {
+        match value {
+            RequestType::Get => reqwest::Method::GET,
+            RequestType::Put => reqwest::Method::PUT,
+            RequestType::Post => reqwest::Method::POST,
+            RequestType::Delete => reqwest::Method::DELETE,
+            RequestType::Head => reqwest::Method::HEAD,
+            RequestType::Options => reqwest::Method::OPTIONS,
         }
     }
 }
@@ -37,6 +56,8 @@ pub struct RequestBuilder {
     pub parameters: HashMap<String, String>,
     pub content_type: Option<String>,
     pub content_md5: Option<String>,
+    /// 发送请求时根据请求体自动计算`content_md5`
+    pub content_md5_auto: bool,
     pub oss_headers: HashMap<String, String>,
 }
 
@@ -61,6 +82,7 @@ impl RequestBuilder {
             parameters: HashMap::new(),
             content_type: None,
             content_md5: None,
+            content_md5_auto: false,
             oss_headers: HashMap::new(),
         }
     }
@@ -76,6 +98,11 @@ impl RequestBuilder {
         self.content_type = Some(content_type.as_ref().to_string());
         self
     }
+    /// 上传时自动计算请求体的MD5，设置`Content-MD5`头并参与签名，OSS会校验上传的数据
+    pub fn with_content_md5_auto(mut self) -> Self {
+        self.content_md5_auto = true;
+        self
+    }
     pub fn with_expire(mut self, expire: Seconds) -> Self {
         self.expire = expire;
         self
@@ -109,6 +136,53 @@ impl RequestBuilder {
         self.parameters.insert("x-oss-ac-forwarded-for".to_string(), "true".to_string());
         self
     }
+    /// 上传/复制文件时设置文件的访问权限
+    pub fn with_object_acl(mut self, acl: Acl) -> Self {
+        self.oss_headers.insert("x-oss-object-acl".to_string(), acl.to_string());
+        self
+    }
+    /// 下载文件的指定范围，`end`为None时表示到文件末尾，包含`end`本身
+    pub fn with_range(mut self, start: u64, end: Option<u64>) -> Self {
+        let end = end.map(|end| end.to_string()).unwrap_or_default();
+        self.headers.insert("Range".to_string(), format!("bytes={}-{}", start, end));
+        self
+    }
+    /// 上传/复制文件时使用服务端加密
+    pub fn with_server_side_encryption(mut self, sse: Sse) -> Self {
+        self.oss_headers.remove("x-oss-server-side-encryption-key-id");
+        self.oss_headers.remove("x-oss-server-side-data-encryption");
+        self.oss_headers.extend(sse.headers());
+        self
+    }
+    /// 上传/复制文件时设置文件标签，key和value会自动URL编码
+    pub fn with_tags<K, V, I>(mut self, tags: I) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.oss_headers.insert("x-oss-tagging".to_string(), encode_tags(tags));
+        self
+    }
+    /// 上传文件/完成分片上传时设置上传回调
+    pub fn with_callback(mut self, callback: &Callback) -> Self {
+        self.oss_headers.insert("x-oss-callback".to_string(), callback.encode());
+        match callback.encode_vars() {
+            Some(vars) => self.oss_headers.insert("x-oss-callback-var".to_string(), vars),
+            None => self.oss_headers.remove("x-oss-callback-var"),
+        };
+        self
+    }
+    /// 图片处理，`process`为`ImageProcess`或`ImageStyle`，用于下载和签名URL
+    pub fn with_image_process<P: Display>(mut self, process: P) -> Self {
+        self.parameters.insert("x-oss-process".to_string(), process.to_string());
+        self
+    }
+    /// 指定操作的文件版本，用于获取、删除、复制历史版本
+    pub fn with_version_id<S: AsRef<str>>(mut self, version_id: S) -> Self {
+        self.parameters.insert("versionId".to_string(), version_id.as_ref().to_string());
+        self
+    }
     pub fn oss_header_put<S: AsRef<str>>(mut self, key: S, value: S) -> Self {
         self.oss_headers.insert(key.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -117,4 +191,40 @@ impl RequestBuilder {
         self.parameters.insert(key.as_ref().to_string(), value.as_ref().to_string());
         self
     }
+
+    /// 是否设置了指定的请求头，HTTP请求头不区分大小写
+    pub(crate) fn has_header(&self, name: &str) -> bool {
+        self.headers.keys().any(|key| key.eq_ignore_ascii_case(name))
+    }
+
+    /// 请求的查询参数，按key排序，值为空的参数只保留key(例如`?acl`)
+    pub(crate) fn query_string(&self) -> String {
+        let mut params = self.parameters.iter().collect::<Vec<_>>();
+        params.sort_by(|a, b| a.0.cmp(b.0));
+        params
+            .into_iter()
+            .map(|(k, v)| {
+                if v.is_empty() {
+                    urlencoding::encode(k).into_owned()
+                } else {
+                    format!("{}={}", urlencoding::encode(k), urlencoding::encode(v))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::request::RequestBuilder;
+
+    #[test]
+    fn test_has_header() {
+        assert!(RequestBuilder::new().with_range(0, Some(10)).has_header("range"));
+        let mut build = RequestBuilder::new();
+        build.headers.insert("range".to_string(), "bytes=0-10".to_string());
+        assert!(build.has_header("Range"));
+        assert!(!RequestBuilder::new().has_header("Range"));
+    }
 }
@@ -0,0 +1,197 @@
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 解冻优先级，只对冷归档和深度冷归档文件生效
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RestoreTier {
+    /// 高优先级，冷归档1小时内完成
+    Expedited,
+    /// 标准，冷归档2~5小时内完成
+    Standard,
+    /// 批量，冷归档5~12小时内完成
+    Bulk,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "RestoreRequest", rename_all = "PascalCase")]
+pub(crate) struct RestoreRequest {
+    pub(crate) days: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) job_parameters: Option<JobParameters>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct JobParameters {
+    pub(crate) tier: RestoreTier,
+}
+
+impl RestoreRequest {
+    pub(crate) fn new(days: u32, tier: Option<RestoreTier>) -> Self {
+        Self {
+            days,
+            job_parameters: tier.map(|tier| JobParameters { tier }),
+        }
+    }
+}
+
+/// 文件的解冻状态，来自响应头`x-oss-restore`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreStatus {
+    /// 正在解冻
+    Ongoing,
+    /// 已解冻，在`expiry`之前可以读取
+    Restored { expiry: DateTime<Utc> },
+}
+
+impl RestoreStatus {
+    /// 解析`ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT"`格式的值
+    pub fn parse(value: &str) -> Option<RestoreStatus> {
+        let mut ongoing = None;
+        let mut expiry = None;
+        let mut rest = value.trim();
+        while let Some((name, after)) = rest.split_once('=') {
+            let after = after.trim_start().strip_prefix('"')?;
+            let (val, after) = after.split_once('"')?;
+            match name.trim().trim_start_matches(',').trim() {
+                "ongoing-request" => ongoing = Some(val == "true"),
+                "expiry-date" => {
+                    expiry = DateTime::parse_from_rfc2822(val).ok().map(|dt| dt.with_timezone(&Utc))
+                }
+                _ => {}
+            }
+            rest = after;
+        }
+        match ongoing? {
+            true => Some(RestoreStatus::Ongoing),
+            false => Some(RestoreStatus::Restored { expiry: expiry? }),
+        }
+    }
+
+    pub fn is_restored(&self) -> bool {
+        matches!(self, RestoreStatus::Restored { .. })
+    }
+}
+
+/// 最小轮询间隔
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// `wait_until_restored`的轮询间隔，从`initial_interval`开始每次翻倍，不超过`max_interval`
+///
+/// # 使用例子
+///
+/// ```
+/// use std::time::Duration;
+/// use aliyun_oss_rust_sdk::restore::RestoreWaiter;
+/// let waiter = RestoreWaiter::new()
+///     .with_initial_interval(Duration::from_secs(60))
+///     .with_max_interval(Duration::from_secs(30 * 60))
+///     .with_timeout(Duration::from_secs(12 * 60 * 60));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RestoreWaiter {
+    pub initial_interval: Duration,
+    pub max_interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for RestoreWaiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RestoreWaiter {
+    /// 默认从10秒开始，最长间隔10分钟，24小时超时
+    pub fn new() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(10),
+            max_interval: Duration::from_secs(10 * 60),
+            timeout: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+    pub fn with_initial_interval(mut self, initial_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self
+    }
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 已等待`elapsed`、上次间隔为`previous`时，下一次的等待时间，超时返回None，
+    /// 间隔最小为1秒，避免设置为0时不停地发送请求
+    pub(crate) fn next_interval(&self, previous: Option<Duration>, elapsed: Duration) -> Option<Duration> {
+        if elapsed >= self.timeout {
+            return None;
+        }
+        let interval = match previous {
+            None => self.initial_interval,
+            Some(previous) => previous.saturating_mul(2),
+        };
+        let interval = interval.min(self.max_interval).max(MIN_INTERVAL);
+        Some(interval.min(self.timeout - elapsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use chrono::{TimeZone, Utc};
+    use crate::restore::{RestoreRequest, RestoreStatus, RestoreTier, RestoreWaiter};
+
+    #[test]
+    fn test_restore_request_xml() {
+        let xml = quick_xml::se::to_string(&RestoreRequest::new(2, Some(RestoreTier::Bulk))).unwrap();
+        assert_eq!(
+            xml,
+            "<RestoreRequest><Days>2</Days><JobParameters><Tier>Bulk</Tier></JobParameters></RestoreRequest>"
+        );
+        let xml = quick_xml::se::to_string(&RestoreRequest::new(1, None)).unwrap();
+        assert_eq!(xml, "<RestoreRequest><Days>1</Days></RestoreRequest>");
+    }
+
+    #[test]
+    fn test_parse_restore_status() {
+        assert_eq!(RestoreStatus::parse(r#"ongoing-request="true""#), Some(RestoreStatus::Ongoing));
+        assert_eq!(
+            RestoreStatus::parse(r#"ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT""#),
+            Some(RestoreStatus::Restored {
+                expiry: Utc.with_ymd_and_hms(2017, 4, 16, 8, 12, 33).unwrap()
+            })
+        );
+        assert_eq!(RestoreStatus::parse(r#"ongoing-request="false""#), None);
+        assert_eq!(RestoreStatus::parse("invalid"), None);
+    }
+
+    #[test]
+    fn test_restore_waiter_backoff() {
+        let waiter = RestoreWaiter::new()
+            .with_initial_interval(Duration::from_secs(10))
+            .with_max_interval(Duration::from_secs(35))
+            .with_timeout(Duration::from_secs(100));
+        let first = waiter.next_interval(None, Duration::ZERO).unwrap();
+        assert_eq!(first, Duration::from_secs(10));
+        let second = waiter.next_interval(Some(first), first).unwrap();
+        assert_eq!(second, Duration::from_secs(20));
+        let third = waiter.next_interval(Some(second), Duration::from_secs(30)).unwrap();
+        assert_eq!(third, Duration::from_secs(35));
+        assert_eq!(
+            waiter.next_interval(Some(third), Duration::from_secs(90)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(waiter.next_interval(Some(third), Duration::from_secs(100)), None);
+
+        let waiter = RestoreWaiter::new()
+            .with_initial_interval(Duration::ZERO)
+            .with_max_interval(Duration::ZERO);
+        let first = waiter.next_interval(None, Duration::ZERO).unwrap();
+        assert_eq!(first, Duration::from_secs(1));
+        assert_eq!(waiter.next_interval(Some(first), first), Some(Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::bucket::BucketEncryption;
+use crate::error::OssError;
+
+/// 服务端加密方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sse {
+    /// OSS完全托管密钥，AES256加密
+    Aes256,
+    /// KMS托管密钥，`key_id`为None时使用OSS默认的托管CMK，
+    /// `data_encryption`为数据加密算法，只支持`SM4`，None时使用AES256
+    Kms { key_id: Option<String>, data_encryption: Option<String> },
+    /// 国密SM4加密
+    Sm4,
+}
+
+impl Display for Sse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sse::Aes256 => write!(f, "AES256"),
+            Sse::Kms { .. } => write!(f, "KMS"),
+            Sse::Sm4 => write!(f, "SM4"),
+        }
+    }
+}
+
+impl Sse {
+    /// 上传时需要设置的`x-oss-server-side-encryption*`请求头
+    pub(crate) fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![("x-oss-server-side-encryption".to_string(), self.to_string())];
+        if let Sse::Kms { key_id, data_encryption } = self {
+            if let Some(key_id) = key_id {
+                headers.push(("x-oss-server-side-encryption-key-id".to_string(), key_id.to_string()));
+            }
+            if let Some(data_encryption) = data_encryption {
+                headers.push(("x-oss-server-side-data-encryption".to_string(), data_encryption.to_string()));
+            }
+        }
+        headers
+    }
+
+    pub(crate) fn from_bucket_encryption(encryption: &BucketEncryption) -> Result<Sse, OssError> {
+        match encryption.sse_algorithm.as_deref() {
+            None => Err(OssError::Err("bucket server side encryption is not enabled".to_string())),
+            Some("AES256") => Ok(Sse::Aes256),
+            Some("KMS") => Ok(Sse::Kms {
+                key_id: encryption.kms_master_key_id.clone().filter(|id| !id.is_empty()),
+                data_encryption: encryption.kms_data_encryption.clone().filter(|alg| !alg.is_empty()),
+            }),
+            Some("SM4") => Ok(Sse::Sm4),
+            Some(other) => Err(OssError::Err(format!("unknown server side encryption algorithm: {}", other))),
+        }
+    }
+
+    pub(crate) fn to_bucket_encryption(&self) -> BucketEncryption {
+        match self {
+            Sse::Kms { key_id, data_encryption } => BucketEncryption {
+                sse_algorithm: Some(self.to_string()),
+                kms_master_key_id: key_id.clone(),
+                kms_data_encryption: data_encryption.clone(),
+            },
+            _ => BucketEncryption {
+                sse_algorithm: Some(self.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "ServerSideEncryptionRule", rename_all = "PascalCase")]
+pub(crate) struct ServerSideEncryptionRule {
+    pub(crate) apply_server_side_encryption_by_default: BucketEncryption,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bucket::BucketEncryption;
+    use crate::sse::{ServerSideEncryptionRule, Sse};
+
+    #[test]
+    fn test_sse_headers() {
+        assert_eq!(
+            Sse::Aes256.headers(),
+            vec![("x-oss-server-side-encryption".to_string(), "AES256".to_string())]
+        );
+        assert_eq!(Sse::Kms { key_id: None, data_encryption: None }.headers().len(), 1);
+        assert_eq!(
+            Sse::Kms { key_id: Some("9468da86-3509-4f8d-a61e-6eab1eac****".to_string()), data_encryption: None }.headers()[1],
+            (
+                "x-oss-server-side-encryption-key-id".to_string(),
+                "9468da86-3509-4f8d-a61e-6eab1eac****".to_string()
+            )
+        );
+        assert_eq!(Sse::Sm4.headers()[0].1, "SM4");
+        assert_eq!(
+            Sse::Kms { key_id: None, data_encryption: Some("SM4".to_string()) }.headers()[1],
+            ("x-oss-server-side-data-encryption".to_string(), "SM4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_server_side_encryption_rule_xml() {
+        let rule = ServerSideEncryptionRule {
+            apply_server_side_encryption_by_default: Sse::Kms { key_id: Some("key".to_string()), data_encryption: None }.to_bucket_encryption(),
+        };
+        assert_eq!(
+            quick_xml::se::to_string(&rule).unwrap(),
+            "<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>KMS</SSEAlgorithm>\
+<KMSMasterKeyID>key</KMSMasterKeyID></ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>"
+        );
+        let rule = ServerSideEncryptionRule {
+            apply_server_side_encryption_by_default: Sse::Aes256.to_bucket_encryption(),
+        };
+        assert_eq!(
+            quick_xml::se::to_string(&rule).unwrap(),
+            "<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>AES256</SSEAlgorithm>\
+</ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>"
+        );
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ServerSideEncryptionRule>
+  <ApplyServerSideEncryptionByDefault>
+    <SSEAlgorithm>KMS</SSEAlgorithm>
+    <KMSMasterKeyID></KMSMasterKeyID>
+  </ApplyServerSideEncryptionByDefault>
+</ServerSideEncryptionRule>"#;
+        let rule: ServerSideEncryptionRule = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            Sse::from_bucket_encryption(&rule.apply_server_side_encryption_by_default).unwrap(),
+            Sse::Kms { key_id: None, data_encryption: None }
+        );
+
+        //获取后再设置不丢失KMS的数据加密算法
+        let xml = r#"<ServerSideEncryptionRule>
+  <ApplyServerSideEncryptionByDefault>
+    <SSEAlgorithm>KMS</SSEAlgorithm>
+    <KMSMasterKeyID>key</KMSMasterKeyID>
+    <KMSDataEncryption>SM4</KMSDataEncryption>
+  </ApplyServerSideEncryptionByDefault>
+</ServerSideEncryptionRule>"#;
+        let rule: ServerSideEncryptionRule = quick_xml::de::from_str(xml).unwrap();
+        let sse = Sse::from_bucket_encryption(&rule.apply_server_side_encryption_by_default).unwrap();
+        assert_eq!(sse, Sse::Kms { key_id: Some("key".to_string()), data_encryption: Some("SM4".to_string()) });
+        let rule = ServerSideEncryptionRule { apply_server_side_encryption_by_default: sse.to_bucket_encryption() };
+        assert_eq!(
+            quick_xml::se::to_string(&rule).unwrap(),
+            "<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>KMS</SSEAlgorithm>\
+<KMSMasterKeyID>key</KMSMasterKeyID><KMSDataEncryption>SM4</KMSDataEncryption>\
+</ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>"
+        );
+        let unknown = BucketEncryption { sse_algorithm: Some("DES".to_string()), ..Default::default() };
+        assert!(Sse::from_bucket_encryption(&unknown).is_err());
+        assert!(Sse::from_bucket_encryption(&BucketEncryption::default()).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use crate::entity::Tag;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "Tagging", rename_all = "PascalCase")]
+pub(crate) struct Tagging {
+    #[serde(default)]
+    pub(crate) tag_set: TagSet,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct TagSet {
+    #[serde(rename = "Tag", default)]
+    pub(crate) tags: Vec<Tag>,
+}
+
+impl Tagging {
+    pub(crate) fn new(tags: Vec<Tag>) -> Self {
+        Self { tag_set: TagSet { tags } }
+    }
+}
+
+/// 转换为`x-oss-tagging`头的格式，key和value分别URL编码后以`k1=v1&k2=v2`拼接，按key排序
+pub(crate) fn encode_tags<K, V, I>(tags: I) -> String
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut tags = tags
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string()))
+        .collect::<Vec<_>>();
+    tags.sort();
+    tags.iter()
+        .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::entity::Tag;
+    use crate::tagging::{encode_tags, Tagging};
+
+    #[test]
+    fn test_encode_tags() {
+        let mut tags = HashMap::new();
+        tags.insert("retention", "7 days");
+        tags.insert("owner", "a&b=c");
+        tags.insert("项目", "归档");
+        assert_eq!(
+            encode_tags(tags),
+            "owner=a%26b%3Dc&retention=7%20days&%E9%A1%B9%E7%9B%AE=%E5%BD%92%E6%A1%A3"
+        );
+        assert_eq!(encode_tags(Vec::<(&str, &str)>::new()), "");
+    }
+
+    #[test]
+    fn test_tagging_xml() {
+        let tagging = Tagging::new(vec![Tag::new("a", "1"), Tag::new("b", "")]);
+        let xml = quick_xml::se::to_string(&tagging).unwrap();
+        assert_eq!(
+            xml,
+            "<Tagging><TagSet><Tag><Key>a</Key><Value>1</Value></Tag><Tag><Key>b</Key><Value/></Tag></TagSet></Tagging>"
+        );
+        let parsed: Tagging = quick_xml::de::from_str(
+            "<Tagging><TagSet><Tag><Key>a</Key><Value>1</Value></Tag><Tag><Key>b</Key></Tag></TagSet></Tagging>",
+        ).unwrap();
+        assert_eq!(parsed.tag_set.tags, vec![Tag::new("a", "1"), Tag::new("b", "")]);
+        let parsed: Tagging = quick_xml::de::from_str("<Tagging><TagSet/></Tagging>").unwrap();
+        assert!(parsed.tag_set.tags.is_empty());
+    }
+}
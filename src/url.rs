@@ -1,17 +1,140 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, TimeZone, Timelike, Utc};
 use reqwest::header::DATE;
-use crate::auth::AuthAPI;
+use crate::auth::{AuthAPI, SUB_RESOURCES};
 use crate::debug;
+use crate::error::OssError;
 use crate::oss::{API, OSS, OSSInfo};
 use crate::request::{RequestBuilder, RequestType};
 
+/// 签名URL的过期时间
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    /// 在指定时间过期
+    At(DateTime<Utc>),
+    /// 从签名时开始计算
+    After(std::time::Duration),
+}
+
+impl Default for Expiry {
+    fn default() -> Self {
+        Expiry::After(std::time::Duration::from_secs(60))
+    }
+}
+
+impl From<DateTime<Utc>> for Expiry {
+    fn from(value: DateTime<Utc>) -> Self {
+        Expiry::At(value)
+    }
+}
+
+impl From<std::time::Duration> for Expiry {
+    fn from(value: std::time::Duration) -> Self {
+        Expiry::After(value)
+    }
+}
+
+impl Expiry {
+    /// 过期时间，超出范围时取最大时间，与URL中的`Expires`一样精确到秒
+    pub(crate) fn at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let at = match self {
+            Expiry::At(at) => *at,
+            Expiry::After(duration) => chrono::Duration::from_std(*duration)
+                .ok()
+                .and_then(|duration| now.checked_add_signed(duration))
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        };
+        at.with_nanosecond(0).unwrap_or(at)
+    }
+}
+
+/// `UrlApi::presign`的选项，设置的请求头参与签名，使用URL时必须带上
+#[derive(Debug, Clone, Default)]
+pub struct PresignOptions {
+    expiry: Expiry,
+    http: bool,
+    cdn: Option<String>,
+    content_type: Option<String>,
+    content_md5: Option<String>,
+    oss_headers: BTreeMap<String, String>,
+    parameters: BTreeMap<String, String>,
+}
+
+impl PresignOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// 过期时间，`DateTime<Utc>`或`std::time::Duration`，默认60秒
+    pub fn with_expiry<E: Into<Expiry>>(mut self, expiry: E) -> Self {
+        self.expiry = expiry.into();
+        self
+    }
+    pub fn with_http(mut self) -> Self {
+        self.http = true;
+        self
+    }
+    pub fn with_cdn<S: AsRef<str>>(mut self, cdn: S) -> Self {
+        self.cdn = Some(cdn.as_ref().to_string());
+        self
+    }
+    pub fn with_content_type<S: AsRef<str>>(mut self, content_type: S) -> Self {
+        self.content_type = Some(content_type.as_ref().to_string());
+        self
+    }
+    /// Base64编码的请求体MD5
+    pub fn with_content_md5<S: AsRef<str>>(mut self, content_md5: S) -> Self {
+        self.content_md5 = Some(content_md5.as_ref().to_string());
+        self
+    }
+    /// 自定义元数据`x-oss-meta-{name}`
+    pub fn with_meta<K: AsRef<str>, V: AsRef<str>>(self, name: K, value: V) -> Self {
+        self.with_oss_header(format!("x-oss-meta-{}", name.as_ref()), value)
+    }
+    /// `x-oss-`开头的请求头
+    pub fn with_oss_header<K: AsRef<str>, V: AsRef<str>>(mut self, name: K, value: V) -> Self {
+        self.oss_headers.insert(name.as_ref().to_lowercase(), value.as_ref().to_string());
+        self
+    }
+    /// 图片处理，`process`为`ImageProcess`或`ImageStyle`
+    pub fn with_image_process<P: std::fmt::Display>(self, process: P) -> Self {
+        self.with_parameter("x-oss-process", process.to_string())
+    }
+    /// 查询参数，例如分片上传的`uploadId`和`partNumber`，值为空时只保留参数名
+    pub fn with_parameter<K: AsRef<str>, V: AsRef<str>>(mut self, name: K, value: V) -> Self {
+        self.parameters.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
+
+/// 签名后的请求，使用`url`时必须带上`headers`中的请求头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresignedRequest {
+    pub method: RequestType,
+    pub url: String,
+    pub expires: DateTime<Utc>,
+    pub headers: BTreeMap<String, String>,
+}
+
+/// 验证通过的签名URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedRequest {
+    /// 以`/`开头的文件key，已解码
+    pub key: String,
+    /// URL的过期时间
+    pub expires: DateTime<Utc>,
+    /// 参与签名的查询参数(子资源，例如`response-content-disposition`)，已解码，
+    /// 未参与签名的参数可能被篡改，不会出现在这里
+    pub parameters: HashMap<String, String>,
+}
+
 pub trait UrlApi: OSSInfo + API {
     /// 获取签名下载URL
     ///
     /// # 使用例子
     ///
-    /// ```
-    /// use aliyun_oss_rust_sdk::oss::{OSS, RequestBuilder};
+    /// ```no_run
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
     /// use aliyun_oss_rust_sdk::url::UrlApi;
     /// let oss = OSS::from_env();//也可以使用OSS::new()方法传递参数
     /// let build = RequestBuilder::new()
@@ -30,8 +153,9 @@ pub trait UrlApi: OSSInfo + API {
     ///
     /// # 使用例子
     ///
-    /// ```
-    /// use aliyun_oss_rust_sdk::oss::{OSS, RequestBuilder};
+    /// ```no_run
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestBuilder;
     /// use aliyun_oss_rust_sdk::url::UrlApi;
     /// let oss = OSS::from_env();//也可以使用OSS::new()方法传递参数
     /// let build = RequestBuilder::new()
@@ -47,6 +171,54 @@ pub trait UrlApi: OSSInfo + API {
     /// ```
     fn sign_upload_url<S: AsRef<str>>(&self, key: S, build: &RequestBuilder) -> String;
     fn sign_url<S: AsRef<str>>(&self, key: S, build: &RequestBuilder) -> String;
+
+    /// 生成任意请求方法的签名URL
+    ///
+    /// # 使用例子
+    ///
+    /// ```
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::RequestType;
+    /// use aliyun_oss_rust_sdk::url::{PresignOptions, UrlApi};
+    /// let oss = OSS::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com", "my-bucket");
+    /// //预签名分片上传的某个分片
+    /// let options = PresignOptions::new()
+    ///     .with_expiry(std::time::Duration::from_secs(3600))
+    ///     .with_parameter("uploadId", "0004B9894A22E5B1888A1E29F823****")
+    ///     .with_parameter("partNumber", "1");
+    /// let request = oss.presign(RequestType::Put, "/big.bin", &options);
+    /// println!("url: {}, headers: {:?}", request.url, request.headers);
+    /// ```
+    fn presign<S: AsRef<str>>(&self, method: RequestType, key: S, options: &PresignOptions) -> PresignedRequest;
+
+    /// 验证`sign_download_url`/`sign_upload_url`生成的签名URL，用于在网关中校验后再转发
+    ///
+    /// # 使用例子
+    ///
+    /// ```
+    /// use aliyun_oss_rust_sdk::oss::OSS;
+    /// use aliyun_oss_rust_sdk::request::{RequestBuilder, RequestType};
+    /// use aliyun_oss_rust_sdk::url::UrlApi;
+    /// let oss = OSS::new("id", "secret", "oss-cn-hangzhou.aliyuncs.com", "my-bucket");
+    /// let url = oss.sign_download_url("/hello.txt", &RequestBuilder::new().with_expire(60));
+    /// let verified = oss.verify_presigned_url(&url, RequestType::Get, chrono::Utc::now()).unwrap();
+    /// assert_eq!(verified.key, "/hello.txt");
+    /// ```
+    fn verify_presigned_url(
+        &self,
+        url: &str,
+        method: RequestType,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedRequest, OssError>;
+
+    /// 同`verify_presigned_url`，`build`提供URL中没有的签名信息：
+    /// 请求方法、上传的Content-Type、`oss_download_allow_ip`限制的客户端IP
+    fn verify_presigned_request(
+        &self,
+        url: &str,
+        build: &RequestBuilder,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedRequest, OssError>;
 }
 
 impl UrlApi for OSS {
@@ -119,13 +291,145 @@ impl UrlApi for OSS {
             params.into_iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<String>>().join("&")
         )
     }
+
+    fn presign<S: AsRef<str>>(&self, method: RequestType, key: S, options: &PresignOptions) -> PresignedRequest {
+        let key = self.format_key(key);
+        let expires = options.expiry.at(Utc::now());
+        let mut build = RequestBuilder::new();
+        build.method = method.clone();
+        build.content_type = options.content_type.clone();
+        build.content_md5 = options.content_md5.clone();
+        build.oss_headers.extend(options.oss_headers.clone());
+        build.parameters.extend(options.parameters.clone());
+        build.headers.insert(DATE.to_string(), expires.timestamp().to_string());
+        let signature = self.sign(key.as_str(), &build);
+        debug!("signature: {}", signature);
+
+        let mut query = options
+            .parameters
+            .iter()
+            .map(|(k, v)| {
+                if v.is_empty() {
+                    urlencoding::encode(k).into_owned()
+                } else {
+                    format!("{}={}", urlencoding::encode(k), urlencoding::encode(v))
+                }
+            })
+            .collect::<Vec<_>>();
+        query.push(format!("Expires={}", expires.timestamp()));
+        query.push(format!("OSSAccessKeyId={}", urlencoding::encode(&self.key_id())));
+        query.push(format!("Signature={}", urlencoding::encode(&signature)));
+        let host = match &options.cdn {
+            Some(cdn) => cdn.to_string(),
+            None => {
+                let schema = if options.http { "http://" } else { "https://" };
+                let endpoint = self.endpoint();
+                let endpoint = endpoint.trim_start_matches("https://").trim_start_matches("http://");
+                format!("{}{}.{}", schema, self.bucket(), endpoint)
+            }
+        };
+        let url = format!("{}{}?{}", host, self.key_urlencode(key), query.join("&"));
+        debug!("presign url: {}", url);
+
+        let mut headers = options.oss_headers.clone();
+        if let Some(content_type) = &options.content_type {
+            headers.insert("Content-Type".to_string(), content_type.to_string());
+        }
+        if let Some(content_md5) = &options.content_md5 {
+            headers.insert("Content-MD5".to_string(), content_md5.to_string());
+        }
+        PresignedRequest {
+            method,
+            url,
+            expires,
+            headers,
+        }
+    }
+
+    fn verify_presigned_url(
+        &self,
+        url: &str,
+        method: RequestType,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedRequest, OssError> {
+        let mut build = RequestBuilder::new();
+        build.method = method;
+        self.verify_presigned_request(url, &build, now)
+    }
+
+    fn verify_presigned_request(
+        &self,
+        url: &str,
+        build: &RequestBuilder,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedRequest, OssError> {
+        let invalid = |msg: &str| OssError::InvalidPresignedUrl(msg.to_string());
+        let decode = |value: &str| {
+            urlencoding::decode(value)
+                .map(|v| v.into_owned())
+                .map_err(|_| invalid("invalid percent encoding"))
+        };
+        let url = url.split('#').next().unwrap_or_default();
+        //去掉scheme和host，只保留path和query
+        let path_and_query = match url.split_once("://") {
+            Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or("/"),
+            None => url,
+        };
+        let (path, query) = path_and_query.split_once('?').unwrap_or((path_and_query, ""));
+        let key = self.format_key(decode(path)?);
+
+        let mut parameters = HashMap::new();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            parameters.insert(decode(k)?, decode(v)?);
+        }
+        let mut take = |name: &str| parameters.remove(name).ok_or_else(|| invalid(&format!("missing {}", name)));
+        let expires = take("Expires")?;
+        let access_key_id = take("OSSAccessKeyId")?;
+        let signature = take("Signature")?;
+        if access_key_id != self.key_id() {
+            return Err(invalid("access key id mismatch"));
+        }
+        let expires = expires
+            .parse::<i64>()
+            .ok()
+            .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+            .ok_or_else(|| invalid("invalid Expires"))?;
+        if expires < now {
+            return Err(invalid("url expired"));
+        }
+        //只有子资源参与签名，其它参数可以被任意追加，不能当作已验证的参数返回
+        parameters.retain(|k, _| SUB_RESOURCES.contains(&k.as_str()));
+
+        let mut build = build.clone();
+        build.headers.insert(DATE.to_string(), expires.timestamp().to_string());
+        build.parameters.extend(parameters.clone());
+        let expected = self.sign(key.as_str(), &build);
+        //固定时间比较，避免通过响应时间猜测签名
+        let matched = expected.len() == signature.len()
+            && expected
+                .bytes()
+                .zip(signature.bytes())
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+                == 0;
+        if !matched {
+            return Err(invalid("signature mismatch"));
+        }
+        Ok(VerifiedRequest {
+            key,
+            expires,
+            parameters,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+    use chrono::{DateTime, TimeZone, Timelike, Utc};
     use crate::oss::OSS;
-    use crate::request::RequestBuilder;
-    use crate::url::UrlApi;
+    use crate::request::{RequestBuilder, RequestType};
+    use crate::url::{Expiry, PresignOptions, UrlApi};
 
     #[inline]
     fn init_log() {
@@ -164,4 +468,118 @@ mod tests {
             &build,
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn verify_presigned_url_test() {
+        let oss = OSS::new("key_id", "key_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let now = Utc::now();
+        let build = RequestBuilder::new()
+            .with_expire(60)
+            .response_content_disposition("中文 name.txt")
+            .oss_download_speed_limit(30);
+        let url = oss.sign_download_url("/dir/中文 文件.txt", &build);
+        let verified = oss.verify_presigned_url(&url, RequestType::Get, now).unwrap();
+        assert_eq!(verified.key, "/dir/中文 文件.txt");
+        assert_eq!(verified.parameters["response-content-disposition"], "attachment;filename=中文 name.txt");
+        assert_eq!(verified.parameters["x-oss-traffic-limit"], "245760");
+        assert!(verified.expires > now);
+
+        let expired = now + chrono::Duration::seconds(120);
+        assert!(oss.verify_presigned_url(&url, RequestType::Get, expired).is_err());
+        assert!(oss.verify_presigned_url(&url, RequestType::Put, now).is_err());
+        let tampered = url.replace("%E6%96%87%E4%BB%B6", "other");
+        assert!(oss.verify_presigned_url(&tampered, RequestType::Get, now).is_err());
+        let other = OSS::new("key_id", "other_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        assert!(other.verify_presigned_url(&url, RequestType::Get, now).is_err());
+        //追加未签名的参数不影响验证，但不会作为已验证的参数返回
+        let appended = format!("{}&role=admin", url);
+        let verified = oss.verify_presigned_url(&appended, RequestType::Get, now).unwrap();
+        assert!(!verified.parameters.contains_key("role"));
+        assert_eq!(verified.parameters.len(), 2);
+
+        let build = RequestBuilder::new()
+            .with_cdn("https://cdn.example.com")
+            .with_content_type("text/plain")
+            .oss_download_allow_ip("10.0.0.1", 32);
+        let url = oss.sign_upload_url("tmp.txt", &build);
+        let mut verify_build = RequestBuilder::new().with_content_type("text/plain").oss_download_allow_ip("10.0.0.1", 32);
+        verify_build.method = RequestType::Put;
+        assert_eq!(oss.verify_presigned_request(&url, &verify_build, now).unwrap().key, "/tmp.txt");
+        let mut verify_build = RequestBuilder::new().with_content_type("text/plain").oss_download_allow_ip("10.0.0.2", 32);
+        verify_build.method = RequestType::Put;
+        assert!(oss.verify_presigned_request(&url, &verify_build, now).is_err());
+    }
+
+    #[test]
+    fn expiry_test() {
+        let now = Utc.timestamp_opt(1700000000, 500_000_000).unwrap();
+        assert_eq!(Expiry::After(Duration::from_secs(60)).at(now).timestamp(), 1700000060);
+        assert_eq!(Expiry::After(Duration::from_secs(60)).at(now).timestamp_subsec_nanos(), 0);
+        assert_eq!(Expiry::At(now).at(now), Utc.timestamp_opt(1700000000, 0).unwrap());
+        assert_eq!(Expiry::After(Duration::MAX).at(now).timestamp(), DateTime::<Utc>::MAX_UTC.timestamp());
+        assert_eq!(Expiry::After(Duration::from_secs(i64::MAX as u64 / 1000)).at(now).timestamp(), DateTime::<Utc>::MAX_UTC.timestamp());
+
+        let oss = OSS::new("key_id", "key_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let request = oss.presign(RequestType::Get, "/a.txt", &PresignOptions::new().with_expiry(now));
+        assert_eq!(request.expires.timestamp_subsec_nanos(), 0);
+        assert!(request.url.contains("Expires=1700000000&"));
+        let request = oss.presign(RequestType::Get, "/a.txt", &PresignOptions::new().with_expiry(Duration::MAX));
+        assert_eq!(request.expires, DateTime::<Utc>::MAX_UTC.with_nanosecond(0).unwrap());
+    }
+
+    #[test]
+    fn presign_test() {
+        let oss = OSS::new("key_id", "key_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let now = Utc::now();
+        let expires = Utc.timestamp_opt(now.timestamp() + 600, 0).unwrap();
+        let options = PresignOptions::new()
+            .with_expiry(expires)
+            .with_content_type("application/octet-stream")
+            .with_content_md5("1B2M2Y8AsgTpgAmY7PhCfg==")
+            .with_meta("Owner", "lake")
+            .with_parameter("uploadId", "upload id")
+            .with_parameter("partNumber", "1");
+        let request = oss.presign(RequestType::Put, "big.bin", &options);
+        assert_eq!(request.expires, expires);
+        assert!(request.url.starts_with("https://bucket.oss-cn-hangzhou.aliyuncs.com/big.bin?partNumber=1&uploadId=upload%20id&Expires="));
+        assert_eq!(request.headers.len(), 3);
+        assert_eq!(request.headers["x-oss-meta-owner"], "lake");
+        assert_eq!(request.headers["Content-MD5"], "1B2M2Y8AsgTpgAmY7PhCfg==");
+
+        let mut build = RequestBuilder::new()
+            .with_content_type("application/octet-stream")
+            .oss_header_put("x-oss-meta-owner", "lake");
+        build.method = RequestType::Put;
+        build.content_md5 = Some("1B2M2Y8AsgTpgAmY7PhCfg==".to_string());
+        let verified = oss.verify_presigned_request(&request.url, &build, now).unwrap();
+        assert_eq!(verified.key, "/big.bin");
+        assert_eq!(verified.parameters["uploadId"], "upload id");
+        build.content_md5 = None;
+        assert!(oss.verify_presigned_request(&request.url, &build, now).is_err());
+
+        let options = PresignOptions::new()
+            .with_expiry(std::time::Duration::from_secs(60))
+            .with_http()
+            .with_parameter("uploads", "");
+        let request = oss.presign(RequestType::Post, "/big.bin", &options);
+        assert!(request.url.starts_with("http://bucket.oss-cn-hangzhou.aliyuncs.com/big.bin?uploads&Expires="));
+        assert!(request.headers.is_empty());
+        assert!(oss.verify_presigned_url(&request.url, RequestType::Post, now).is_ok());
+    }
+
+    #[test]
+    fn presign_image_process_test() {
+        use crate::image::{ImageProcess, ImageStyle, Resize, ResizeMode};
+        let oss = OSS::new("key_id", "key_secret", "oss-cn-hangzhou.aliyuncs.com", "bucket");
+        let process = ImageProcess::new().with_resize(Resize::new(ResizeMode::Lfit).with_width(100));
+        let request = oss.presign(RequestType::Get, "/a.jpg", &PresignOptions::new().with_image_process(&process));
+        assert!(request.url.contains("?x-oss-process=image%2Fresize%2Cm_lfit%2Cw_100&Expires="));
+        let verified = oss.verify_presigned_url(&request.url, RequestType::Get, Utc::now()).unwrap();
+        assert_eq!(verified.parameters["x-oss-process"], "image/resize,m_lfit,w_100");
+
+        let build = RequestBuilder::new().with_image_process(ImageStyle::new("small"));
+        let url = oss.sign_download_url("/a.jpg", &build);
+        assert!(url.contains("x-oss-process=style%2Fsmall"));
+        assert!(oss.verify_presigned_url(&url, RequestType::Get, Utc::now()).is_ok());
+    }
+}
@@ -1,6 +1,7 @@
 use std::io::{BufReader, Read};
 use base64::engine::general_purpose;
 use base64::{Engine};
+use md5::{Digest, Md5};
 
 pub fn read_file<S: AsRef<str>>(file_name: S) -> Result<Vec<u8>, std::io::Error> {
     let file = std::fs::File::open(file_name.as_ref())?;
@@ -16,3 +17,31 @@ pub fn base64_encode<S>(content: S) -> String
 {
     general_purpose::STANDARD.encode(content)
 }
+
+#[cfg(any(feature = "encryption", feature = "callback-verify"))]
+pub fn base64_decode<S>(content: S) -> Result<Vec<u8>, base64::DecodeError>
+    where
+        S: AsRef<[u8]>,
+{
+    general_purpose::STANDARD.decode(content)
+}
+
+/// 请求头`Content-MD5`的值，即MD5摘要的base64编码
+pub fn md5_base64<S: AsRef<[u8]>>(content: S) -> String {
+    base64_encode(Md5::digest(content.as_ref()))
+}
+
+pub fn hex<S: AsRef<[u8]>>(bytes: S) -> String {
+    bytes.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::md5_base64;
+
+    #[test]
+    fn test_md5_base64() {
+        assert_eq!(md5_base64(""), "1B2M2Y8AsgTpgAmY7PhCfg==");
+        assert_eq!(md5_base64("0123456789"), "eB5eJF1ptWaXm4bijSPyxw==");
+    }
+}
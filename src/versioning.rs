@@ -0,0 +1,351 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::bucket::{Owner, StorageClass, VersioningStatus};
+use crate::metadata::ObjectType;
+use crate::request::RequestBuilder;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "VersioningConfiguration", rename_all = "PascalCase")]
+pub(crate) struct VersioningConfiguration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<VersioningStatus>,
+}
+
+/// 列举文件版本的查询条件
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectVersionsBuilder {
+    pub prefix: Option<String>,
+    pub delimiter: Option<String>,
+    pub key_marker: Option<String>,
+    pub version_id_marker: Option<String>,
+    pub max_keys: Option<u32>,
+}
+
+impl ListObjectVersionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_prefix<S: AsRef<str>>(mut self, prefix: S) -> Self {
+        self.prefix = Some(prefix.as_ref().to_string());
+        self
+    }
+    /// 按目录分组，例如`/`
+    pub fn with_delimiter<S: AsRef<str>>(mut self, delimiter: S) -> Self {
+        self.delimiter = Some(delimiter.as_ref().to_string());
+        self
+    }
+    pub fn with_key_marker<S: AsRef<str>>(mut self, key_marker: S) -> Self {
+        self.key_marker = Some(key_marker.as_ref().to_string());
+        self
+    }
+    /// 需要和`with_key_marker`一起使用
+    pub fn with_version_id_marker<S: AsRef<str>>(mut self, version_id_marker: S) -> Self {
+        self.version_id_marker = Some(version_id_marker.as_ref().to_string());
+        self
+    }
+    /// 最大返回数量，取值1~1000，默认100
+    pub fn with_max_keys(mut self, max_keys: u32) -> Self {
+        self.max_keys = Some(max_keys);
+        self
+    }
+
+    pub(crate) fn apply(&self, build: RequestBuilder) -> RequestBuilder {
+        let mut build = build.parameters_put("versions", "");
+        if let Some(prefix) = &self.prefix {
+            build = build.parameters_put("prefix", prefix.as_str());
+        }
+        if let Some(delimiter) = &self.delimiter {
+            build = build.parameters_put("delimiter", delimiter.as_str());
+        }
+        if let Some(key_marker) = &self.key_marker {
+            build = build.parameters_put("key-marker", key_marker.as_str());
+        }
+        if let Some(version_id_marker) = &self.version_id_marker {
+            build = build.parameters_put("version-id-marker", version_id_marker.as_str());
+        }
+        if let Some(max_keys) = self.max_keys {
+            build = build.parameters_put("max-keys".to_string(), max_keys.to_string());
+        }
+        build
+    }
+}
+
+/// 文件的一个版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectVersion {
+    pub key: String,
+    pub version_id: String,
+    #[serde(default)]
+    pub is_latest: bool,
+    #[serde(default)]
+    pub last_modified: String,
+    #[serde(rename = "ETag", default, deserialize_with = "trim_etag")]
+    pub etag: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub storage_class: Option<StorageClass>,
+    #[serde(rename = "Type", default)]
+    pub object_type: Option<ObjectType>,
+    #[serde(default)]
+    pub owner: Owner,
+}
+
+/// 删除标记，表示文件在该版本被删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteMarker {
+    pub key: String,
+    pub version_id: String,
+    #[serde(default)]
+    pub is_latest: bool,
+    #[serde(default)]
+    pub last_modified: String,
+    #[serde(default)]
+    pub owner: Owner,
+}
+
+/// 文件版本或删除标记
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ObjectVersionEntry {
+    Version(ObjectVersion),
+    DeleteMarker(DeleteMarker),
+}
+
+impl ObjectVersionEntry {
+    pub fn key(&self) -> &str {
+        match self {
+            ObjectVersionEntry::Version(v) => v.key.as_str(),
+            ObjectVersionEntry::DeleteMarker(d) => d.key.as_str(),
+        }
+    }
+    pub fn version_id(&self) -> &str {
+        match self {
+            ObjectVersionEntry::Version(v) => v.version_id.as_str(),
+            ObjectVersionEntry::DeleteMarker(d) => d.version_id.as_str(),
+        }
+    }
+    pub fn last_modified(&self) -> &str {
+        match self {
+            ObjectVersionEntry::Version(v) => v.last_modified.as_str(),
+            ObjectVersionEntry::DeleteMarker(d) => d.last_modified.as_str(),
+        }
+    }
+    pub fn is_latest(&self) -> bool {
+        match self {
+            ObjectVersionEntry::Version(v) => v.is_latest,
+            ObjectVersionEntry::DeleteMarker(d) => d.is_latest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CommonPrefix {
+    pub prefix: String,
+}
+
+/// 列举文件版本的结果，`is_truncated`为true时使用`next_page`获取下一页
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "ListVersionsResult", rename_all = "PascalCase")]
+pub struct ListObjectVersionsResult {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub key_marker: Option<String>,
+    #[serde(default)]
+    pub version_id_marker: Option<String>,
+    #[serde(default)]
+    pub max_keys: Option<u32>,
+    #[serde(default)]
+    pub delimiter: Option<String>,
+    #[serde(default)]
+    pub is_truncated: bool,
+    #[serde(default)]
+    pub next_key_marker: Option<String>,
+    #[serde(default)]
+    pub next_version_id_marker: Option<String>,
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+    /// `Version`和`DeleteMarker`在响应中交替出现，按服务端返回的顺序保存
+    #[serde(rename = "$value", default)]
+    entries: Vec<ObjectVersionEntry>,
+}
+
+impl ListObjectVersionsResult {
+    /// 版本和删除标记，保持服务端返回的顺序(按key升序、同一key从新到旧)
+    pub fn entries(&self) -> Vec<ObjectVersionEntry> {
+        self.entries.clone()
+    }
+
+    pub fn versions(&self) -> Vec<&ObjectVersion> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                ObjectVersionEntry::Version(v) => Some(v),
+                ObjectVersionEntry::DeleteMarker(_) => None,
+            })
+            .collect()
+    }
+
+    pub fn delete_markers(&self) -> Vec<&DeleteMarker> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                ObjectVersionEntry::DeleteMarker(d) => Some(d),
+                ObjectVersionEntry::Version(_) => None,
+            })
+            .collect()
+    }
+
+    /// 下一页的查询条件，没有更多数据时返回None
+    pub fn next_page(&self, build: &ListObjectVersionsBuilder) -> Option<ListObjectVersionsBuilder> {
+        if !self.is_truncated {
+            return None;
+        }
+        let mut next = build.clone().with_key_marker(self.next_key_marker.clone()?);
+        next.version_id_marker = self.next_version_id_marker.clone();
+        Some(next)
+    }
+}
+
+fn trim_etag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let etag = String::deserialize(deserializer)?;
+    Ok(etag.trim_matches('"').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bucket::VersioningStatus;
+    use crate::metadata::ObjectType;
+    use crate::versioning::{ListObjectVersionsBuilder, ListObjectVersionsResult, ObjectVersionEntry, VersioningConfiguration};
+
+    #[test]
+    fn test_versioning_configuration_xml() {
+        let config = VersioningConfiguration { status: Some(VersioningStatus::Enabled) };
+        assert_eq!(
+            quick_xml::se::to_string(&config).unwrap(),
+            "<VersioningConfiguration><Status>Enabled</Status></VersioningConfiguration>"
+        );
+        let config: VersioningConfiguration = quick_xml::de::from_str("<VersioningConfiguration/>").unwrap();
+        assert_eq!(config.status, None);
+    }
+
+    #[test]
+    fn test_list_object_versions_result_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListVersionsResult>
+  <Name>examplebucket</Name>
+  <Prefix></Prefix>
+  <KeyMarker>example</KeyMarker>
+  <VersionIdMarker>CAEQMxiBgICbof2D0BYiIGRhZjgwMzJiMjA3MjQ0ODE5MWYxZDYwMzJlZjU1****</VersionIdMarker>
+  <MaxKeys>100</MaxKeys>
+  <Delimiter></Delimiter>
+  <IsTruncated>true</IsTruncated>
+  <NextKeyMarker>config.json</NextKeyMarker>
+  <NextVersionIdMarker>CAEQGhiBgIC_</NextVersionIdMarker>
+  <DeleteMarker>
+    <Key>config.json</Key>
+    <VersionId>v3</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2019-04-09T07:27:30.000Z</LastModified>
+    <Owner>
+      <ID>1234512528586****</ID>
+      <DisplayName>12345125285864390</DisplayName>
+    </Owner>
+  </DeleteMarker>
+  <Version>
+    <Key>config.json</Key>
+    <VersionId>v2</VersionId>
+    <IsLatest>false</IsLatest>
+    <LastModified>2019-04-09T07:27:29.000Z</LastModified>
+    <ETag>"250F8A0AE989679A22926A875F0A2****"</ETag>
+    <Type>Normal</Type>
+    <Size>93731</Size>
+    <StorageClass>Standard</StorageClass>
+  </Version>
+  <Version>
+    <Key>config.json</Key>
+    <VersionId>v1</VersionId>
+    <IsLatest>false</IsLatest>
+    <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+    <ETag>"350F8A0AE989679A22926A875F0A2****"</ETag>
+    <Type>Normal</Type>
+    <Size>93731</Size>
+    <StorageClass>Standard</StorageClass>
+  </Version>
+</ListVersionsResult>"#;
+        let result: ListObjectVersionsResult = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(result.versions().len(), 2);
+        assert_eq!(result.delete_markers().len(), 1);
+        assert_eq!(result.versions()[0].etag, "250F8A0AE989679A22926A875F0A2****");
+        assert_eq!(result.versions()[0].object_type, Some(ObjectType::Normal));
+        let entries = result.entries();
+        assert!(matches!(entries[0], ObjectVersionEntry::DeleteMarker(_)));
+        assert_eq!(entries.iter().map(|e| e.version_id()).collect::<Vec<_>>(), vec!["v3", "v2", "v1"]);
+        let next = result.next_page(&ListObjectVersionsBuilder::new().with_max_keys(3)).unwrap();
+        assert_eq!(next.key_marker.as_deref(), Some("config.json"));
+        assert_eq!(next.version_id_marker.as_deref(), Some("CAEQGhiBgIC_"));
+        assert_eq!(next.max_keys, Some(3));
+    }
+
+    #[test]
+    fn test_list_object_versions_interleaved_xml() {
+        //不同key的版本和删除标记交替出现，同一秒内的版本依赖服务端顺序
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListVersionsResult>
+  <Name>examplebucket</Name>
+  <IsTruncated>false</IsTruncated>
+  <Version>
+    <Key>a.json</Key>
+    <VersionId>a2</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+    <ETag>"A2"</ETag>
+    <Size>2</Size>
+  </Version>
+  <Version>
+    <Key>a.json</Key>
+    <VersionId>a1</VersionId>
+    <IsLatest>false</IsLatest>
+    <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+    <ETag>"A1"</ETag>
+    <Size>1</Size>
+  </Version>
+  <DeleteMarker>
+    <Key>b.json</Key>
+    <VersionId>b2</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2019-04-09T07:27:30.000Z</LastModified>
+  </DeleteMarker>
+  <Version>
+    <Key>b.json</Key>
+    <VersionId>b1</VersionId>
+    <IsLatest>false</IsLatest>
+    <LastModified>2019-04-09T07:27:29.000Z</LastModified>
+    <ETag>"B1"</ETag>
+    <Size>1</Size>
+  </Version>
+  <DeleteMarker>
+    <Key>c.json</Key>
+    <VersionId>c1</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2019-04-09T07:27:31.000Z</LastModified>
+  </DeleteMarker>
+  <CommonPrefixes>
+    <Prefix>dir/</Prefix>
+  </CommonPrefixes>
+</ListVersionsResult>"#;
+        let result: ListObjectVersionsResult = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            result.entries().iter().map(|e| e.version_id()).collect::<Vec<_>>(),
+            vec!["a2", "a1", "b2", "b1", "c1"]
+        );
+        assert_eq!(result.versions().len(), 3);
+        assert_eq!(result.delete_markers().len(), 2);
+        assert_eq!(result.common_prefixes[0].prefix, "dir/");
+        assert!(result.next_page(&ListObjectVersionsBuilder::new()).is_none());
+    }
+}